@@ -1,47 +1,126 @@
 use crate::{
+    combat_log::{CombatLogEvent, CombatLogEventKind},
     creature::Creature,
     effect::{Effect, LastingEffect, LastingEffects, PerformEffect},
+    health::Health,
     intersect_line_aabb::is_intersecting,
     level::Obstacle,
     mana::{Mana, RegenManaCooldown},
-    position::ChangingPosition,
+    position::{ChangingPosition, Facing},
+    projectile::Projectile,
+    sprite::Sprite,
     AppState,
 };
 use bevy::prelude::*;
-use std::collections::HashMap;
+use serde::Deserialize;
+use std::{collections::HashMap, fmt};
 
 const ABILITY_GLOBAL_COOLDOWN_DURATION: f32 = 1.5;
+const CREATURE_RADIUS: f32 = Sprite::SIZE / 2.0;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Deserialize)]
 pub struct Ability {
     pub id: u8,
-    pub name: &'static str,
+    pub name: String,
     pub mana_points: u16,
+    #[serde(default)]
     pub cast_duration: f32,
-    pub cooldown_duration: f32,
+    /// Cooldown started when the ability is performed, `None` if the ability has no cooldown.
+    #[serde(default)]
+    pub cooldown_duration: Option<f32>,
     pub range: f32,
-    pub effect: (Effect, AbilityTargetMode),
-    pub secondary_effect: Option<(Effect, AbilityTargetMode)>,
+    pub effects: Vec<(Effect, AbilityTargetMode)>,
+    /// Projectile carrying the effects to the target, `None` if the effects land instantly.
+    #[serde(default)]
+    pub projectile: Option<AbilityProjectile>,
 }
 
 impl Ability {
-    fn requires_target(&self) -> bool {
-        let (_, target_mode) = self.effect;
-        let effect_requires_target = target_mode == AbilityTargetMode::Single;
+    pub fn requires_target(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|(_, target_mode)| *target_mode == AbilityTargetMode::Single)
+    }
 
-        let secondary_effect_requires_target = match self.secondary_effect {
-            Some((_, secondary_target_mode)) => secondary_target_mode == AbilityTargetMode::Single,
-            None => false,
-        };
+    pub fn requires_target_position(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|(_, target_mode)| matches!(target_mode, AbilityTargetMode::Ground(_)))
+    }
 
-        effect_requires_target || secondary_effect_requires_target
+    /// Returns the radius of the first effect placed on the ground.
+    pub fn ground_radius(&self) -> Option<f32> {
+        self.effects
+            .iter()
+            .find_map(|(_, target_mode)| match target_mode {
+                AbilityTargetMode::Ground(radius) => Some(*radius),
+                _ => None,
+            })
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Deserialize)]
+pub struct AbilityProjectile {
+    pub speed: f32,
+    pub sprite: Sprite,
+}
+
+/// Which creatures an effect lands on. Shapes extend up to the ability's range, from the caster unless stated.
+#[derive(Clone, Copy, PartialEq, Deserialize)]
 pub enum AbilityTargetMode {
     Single,
+    /// Circle around the caster.
     Area,
+    /// Circle with a radius, placed at a target position in range.
+    Ground(f32),
+    /// Cone with an angle in degrees, towards the facing direction.
+    Cone(f32),
+    /// Line with a width, towards the facing direction.
+    Line(f32),
+    /// Ring with an inner radius, around the caster.
+    Ring(f32),
+}
+
+impl AbilityTargetMode {
+    /// Returns whether a creature at the position overlaps the shape, treating creatures as circles.
+    fn overlaps(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        target_position: Option<Vec2>,
+        range: f32,
+        position: Vec2,
+    ) -> bool {
+        let offset = position - origin;
+        let distance = offset.length();
+
+        match *self {
+            Self::Single => false,
+            Self::Area => distance <= range + CREATURE_RADIUS,
+            Self::Ground(radius) => match target_position {
+                Some(target_position) => {
+                    position.distance(target_position) <= radius + CREATURE_RADIUS
+                }
+                None => false,
+            },
+            Self::Cone(angle) => {
+                distance <= CREATURE_RADIUS
+                    || (distance <= range + CREATURE_RADIUS
+                        && direction.angle_between(offset).abs() <= angle.to_radians() / 2.0)
+            }
+            Self::Line(width) => {
+                let length = offset.dot(direction);
+                let perpendicular_distance = offset.perp_dot(direction).abs();
+
+                length >= -CREATURE_RADIUS
+                    && length <= range + CREATURE_RADIUS
+                    && perpendicular_distance <= width / 2.0 + CREATURE_RADIUS
+            }
+            Self::Ring(inner_radius) => {
+                distance >= inner_radius - CREATURE_RADIUS && distance <= range + CREATURE_RADIUS
+            }
+        }
+    }
 }
 
 /// Event to initiate an ability, if possible.
@@ -49,6 +128,8 @@ pub struct TryAbility {
     pub source: Entity,
     pub ability: Ability,
     pub target: Option<Entity>,
+    /// Position to place ground effects at.
+    pub target_position: Option<Vec2>,
 }
 
 /// Event to cancel casting ability.
@@ -56,11 +137,69 @@ pub struct CancelCastAbility {
     pub source: Entity,
 }
 
+/// Event to communicate an ability being rejected when tried.
+pub struct TryAbilityFailed {
+    pub source: Entity,
+    pub ability_id: u8,
+    pub reason: AbilityFailedReason,
+}
+
+/// Event to communicate an ability failing to be performed once its cast is over. Nothing is spent on failure.
+pub struct PerformAbilityFailed {
+    pub source: Entity,
+    pub ability_id: u8,
+    pub reason: AbilityFailedReason,
+}
+
+/// Why an ability was rejected when tried, or failed once its cast was over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbilityFailedReason {
+    Casting,
+    Silenced,
+    Moving,
+    Cooldown,
+    GlobalCooldown,
+    NotEnoughMana,
+    NoTarget,
+    NoTargetPosition,
+    TargetDead,
+    OutOfRange,
+    NotInSight,
+}
+
+impl From<TargetPositionError> for AbilityFailedReason {
+    fn from(target_position_error: TargetPositionError) -> Self {
+        match target_position_error {
+            TargetPositionError::Range => Self::OutOfRange,
+            TargetPositionError::Sight => Self::NotInSight,
+        }
+    }
+}
+
+impl fmt::Display for AbilityFailedReason {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Casting => write!(formatter, "Casting another ability."),
+            Self::Silenced => write!(formatter, "Silenced."),
+            Self::Moving => write!(formatter, "Can't cast while moving."),
+            Self::Cooldown => write!(formatter, "Ability is not ready yet."),
+            Self::GlobalCooldown => write!(formatter, "In global cooldown."),
+            Self::NotEnoughMana => write!(formatter, "Not enough mana."),
+            Self::NoTarget => write!(formatter, "No target."),
+            Self::NoTargetPosition => write!(formatter, "No target position."),
+            Self::TargetDead => write!(formatter, "Target is dead."),
+            Self::OutOfRange => write!(formatter, "Target out of range."),
+            Self::NotInSight => write!(formatter, "Target not in line of sight."),
+        }
+    }
+}
+
 /// Internal event to perform an ability via a try ability event.
 struct PerformAbility {
     source: Entity,
     ability: Ability,
     target: Option<Entity>,
+    target_position: Option<Vec2>,
 }
 
 /// Component to store cast duration for an ability.
@@ -68,25 +207,36 @@ struct PerformAbility {
 pub struct CastAbility {
     pub ability: Ability,
     pub target: Option<Entity>,
+    pub target_position: Option<Vec2>,
     pub duration_timer: Timer,
 }
 
 impl CastAbility {
-    pub fn new(ability: Ability, target: Option<Entity>) -> Self {
+    pub fn new(ability: Ability, target: Option<Entity>, target_position: Option<Vec2>) -> Self {
+        let duration_timer = Timer::from_seconds(ability.cast_duration, TimerMode::Once);
+
         Self {
             ability,
             target,
-            duration_timer: Timer::from_seconds(ability.cast_duration, TimerMode::Once),
+            target_position,
+            duration_timer,
         }
     }
 }
 
 /// Component to disable all abilities for a duration after using any ability.
 #[derive(Component)]
-struct AbilityGlobalCooldown {
+pub struct AbilityGlobalCooldown {
     duration_timer: Timer,
 }
 
+impl AbilityGlobalCooldown {
+    /// Returns the timer of the global cooldown, to tell how much of it remains.
+    pub fn duration_timer(&self) -> &Timer {
+        &self.duration_timer
+    }
+}
+
 impl Default for AbilityGlobalCooldown {
     fn default() -> Self {
         Self {
@@ -102,9 +252,20 @@ pub struct AbilityCooldowns {
 }
 
 impl AbilityCooldowns {
-    fn push(&mut self, ability: Ability) {
+    pub fn contains(&self, ability_id: u8) -> bool {
+        self.instances_by_id.contains_key(&ability_id)
+    }
+
+    /// Returns the timer of the ability's cooldown, to tell how much of it remains, `None` if not in cooldown.
+    pub fn duration_timer(&self, ability_id: u8) -> Option<&Timer> {
         self.instances_by_id
-            .insert(ability.id, AbilityCooldownInstance::new(ability));
+            .get(&ability_id)
+            .map(|ability_cooldown_instance| &ability_cooldown_instance.duration_timer)
+    }
+
+    fn push(&mut self, ability_id: u8, cooldown_duration: f32) {
+        self.instances_by_id
+            .insert(ability_id, AbilityCooldownInstance::new(cooldown_duration));
     }
 }
 
@@ -113,11 +274,11 @@ struct AbilityCooldownInstance {
 }
 
 impl AbilityCooldownInstance {
-    fn new(ability: Ability) -> Self {
-        assert!(ability.cooldown_duration > 0.0);
+    fn new(cooldown_duration: f32) -> Self {
+        assert!(cooldown_duration > 0.0);
 
         Self {
-            duration_timer: Timer::from_seconds(ability.cooldown_duration, TimerMode::Once),
+            duration_timer: Timer::from_seconds(cooldown_duration, TimerMode::Once),
         }
     }
 }
@@ -127,8 +288,10 @@ pub struct AbilityPlugin;
 impl Plugin for AbilityPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<TryAbility>()
+            .add_event::<TryAbilityFailed>()
             .add_event::<CancelCastAbility>()
             .add_event::<PerformAbility>()
+            .add_event::<PerformAbilityFailed>()
             .add_system_set(
                 SystemSet::on_update(AppState::Game)
                     .with_system(remove_ability_global_cooldown_system)
@@ -171,11 +334,15 @@ fn remove_ability_cooldowns_system(time: Res<Time>, mut query: Query<&mut Abilit
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn try_ability_system(
     mut commands: Commands,
+    time: Res<Time>,
     mut try_ability_event_reader: EventReader<TryAbility>,
+    mut try_ability_failed_event_writer: EventWriter<TryAbilityFailed>,
     mut perform_ability_event_writer: EventWriter<PerformAbility>,
-    mut query: Query<(
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
+    query: Query<(
         &Mana,
         &AbilityCooldowns,
         &LastingEffects,
@@ -188,124 +355,151 @@ fn try_ability_system(
     obstacle_query: Query<&Transform, With<Obstacle>>,
 ) {
     for try_ability in try_ability_event_reader.iter() {
-        let (
-            mana,
-            ability_cooldowns,
-            lasting_effects,
-            transform,
-            cast_ability,
-            ability_global_cooldown,
-            changing_position,
-        ) = query.get_mut(try_ability.source).unwrap();
-
-        if cast_ability.is_some() {
-            info!("Casting another ability.");
+        if let Err(reason) = verify_try_ability(try_ability, &query, &target_query, &obstacle_query)
+        {
+            try_ability_failed_event_writer.send(TryAbilityFailed {
+                source: try_ability.source,
+                ability_id: try_ability.ability.id,
+                reason,
+            });
 
             continue;
         }
 
-        if lasting_effects
-            .instances
-            .iter()
-            .any(|instance| matches!(instance.effect, LastingEffect::Silence))
-        {
-            info!("Silenced.");
+        commands
+            .entity(try_ability.source)
+            .insert(AbilityGlobalCooldown::default());
 
-            continue;
+        if try_ability.ability.cast_duration > 0.0 {
+            commands.entity(try_ability.source).insert(CastAbility::new(
+                try_ability.ability.clone(),
+                try_ability.target,
+                try_ability.target_position,
+            ));
+
+            combat_log_event_writer.send(CombatLogEvent::new(
+                &time,
+                try_ability.source,
+                try_ability.target,
+                CombatLogEventKind::CastStarted(try_ability.ability.name.clone()),
+            ));
+        } else {
+            perform_ability_event_writer.send(PerformAbility {
+                source: try_ability.source,
+                ability: try_ability.ability.clone(),
+                target: try_ability.target,
+                target_position: try_ability.target_position,
+            });
         }
+    }
+}
 
-        if changing_position.is_some() && try_ability.ability.cast_duration > 0.0 {
-            info!("Moving.");
+#[allow(clippy::type_complexity)]
+fn verify_try_ability(
+    try_ability: &TryAbility,
+    query: &Query<(
+        &Mana,
+        &AbilityCooldowns,
+        &LastingEffects,
+        &Transform,
+        Option<&CastAbility>,
+        Option<&AbilityGlobalCooldown>,
+        Option<&ChangingPosition>,
+    )>,
+    target_query: &Query<&Transform>,
+    obstacle_query: &Query<&Transform, With<Obstacle>>,
+) -> Result<(), AbilityFailedReason> {
+    let (
+        mana,
+        ability_cooldowns,
+        lasting_effects,
+        transform,
+        cast_ability,
+        ability_global_cooldown,
+        changing_position,
+    ) = query.get(try_ability.source).unwrap();
+
+    if cast_ability.is_some() {
+        return Err(AbilityFailedReason::Casting);
+    }
 
-            continue;
-        }
+    if lasting_effects
+        .instances
+        .iter()
+        .any(|instance| matches!(instance.effect, LastingEffect::Silence))
+    {
+        return Err(AbilityFailedReason::Silenced);
+    }
 
-        if ability_cooldowns
-            .instances_by_id
-            .contains_key(&try_ability.ability.id)
-        {
-            info!("Ability in cooldown.");
+    if changing_position.is_some() && try_ability.ability.cast_duration > 0.0 {
+        return Err(AbilityFailedReason::Moving);
+    }
 
-            continue;
-        }
+    if ability_cooldowns.contains(try_ability.ability.id) {
+        return Err(AbilityFailedReason::Cooldown);
+    }
 
-        if ability_global_cooldown.is_some() {
-            info!("In global cooldown.");
+    if ability_global_cooldown.is_some() {
+        return Err(AbilityFailedReason::GlobalCooldown);
+    }
 
-            continue;
-        }
+    if try_ability.ability.mana_points > mana.points {
+        return Err(AbilityFailedReason::NotEnoughMana);
+    }
 
-        if try_ability.ability.mana_points > mana.points {
-            info!("Not enough mana.");
+    let position = transform.translation.truncate();
 
-            continue;
-        }
+    if try_ability.ability.requires_target() {
+        let target = try_ability.target.ok_or(AbilityFailedReason::NoTarget)?;
 
-        if try_ability.ability.requires_target() {
-            let target = match try_ability.target {
-                Some(result) => result,
-                None => {
-                    info!("No target.");
+        if target != try_ability.source {
+            let target_transform = target_query
+                .get(target)
+                .map_err(|_| AbilityFailedReason::TargetDead)?;
 
-                    continue;
-                }
-            };
-
-            if target != try_ability.source {
-                let position = transform.translation.truncate();
-
-                let target_transform = target_query.get(target).unwrap();
-                let target_position = target_transform.translation.truncate();
-
-                match verify_target_position(
-                    position,
-                    target_position,
-                    try_ability.ability.range,
-                    &obstacle_query,
-                ) {
-                    Err(TargetPositionError::Range) => {
-                        info!("Out of range.");
-
-                        continue;
-                    }
-                    Err(TargetPositionError::Sight) => {
-                        info!("Not in line of sight.");
-
-                        continue;
-                    }
-                    Ok(_) => (),
-                }
-            }
+            verify_target_position(
+                position,
+                target_transform.translation.truncate(),
+                try_ability.ability.range,
+                obstacle_query,
+            )?;
         }
+    }
 
-        commands
-            .entity(try_ability.source)
-            .insert(AbilityGlobalCooldown::default());
+    if try_ability.ability.requires_target_position() {
+        let target_position = try_ability
+            .target_position
+            .ok_or(AbilityFailedReason::NoTargetPosition)?;
 
-        if try_ability.ability.cast_duration > 0.0 {
-            commands
-                .entity(try_ability.source)
-                .insert(CastAbility::new(try_ability.ability, try_ability.target));
-        } else {
-            perform_ability_event_writer.send(PerformAbility {
-                source: try_ability.source,
-                ability: try_ability.ability,
-                target: try_ability.target,
-            });
-        }
+        verify_target_position(
+            position,
+            target_position,
+            try_ability.ability.range,
+            obstacle_query,
+        )?;
     }
+
+    Ok(())
 }
 
 fn cast_ability_system(
     mut commands: Commands,
     time: Res<Time>,
     mut perform_ability_event_writer: EventWriter<PerformAbility>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
     mut query: Query<(Entity, &mut CastAbility, Option<&ChangingPosition>)>,
 ) {
     for (entity, mut cast_ability, changing_position) in query.iter_mut() {
         if changing_position.is_some() {
             commands.entity(entity).remove::<CastAbility>();
 
+            combat_log_event_writer.send(CombatLogEvent::new(
+                &time,
+                entity,
+                cast_ability.target,
+                CombatLogEventKind::CastInterrupted(cast_ability.ability.name.clone()),
+            ));
+
             continue;
         }
 
@@ -316,8 +510,9 @@ fn cast_ability_system(
 
             perform_ability_event_writer.send(PerformAbility {
                 source: entity,
-                ability: cast_ability.ability,
+                ability: cast_ability.ability.clone(),
                 target: cast_ability.target,
+                target_position: cast_ability.target_position,
             });
         }
     }
@@ -325,87 +520,222 @@ fn cast_ability_system(
 
 fn cancel_cast_ability_system(
     mut commands: Commands,
+    time: Res<Time>,
     mut cancel_cast_ability_event_reader: EventReader<CancelCastAbility>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
+    query: Query<&CastAbility>,
 ) {
     for cancel_cast_ability in cancel_cast_ability_event_reader.iter() {
+        let cast_ability = match query.get(cancel_cast_ability.source) {
+            Ok(result) => result,
+            Err(_) => continue,
+        };
+
         commands
             .entity(cancel_cast_ability.source)
             .remove::<CastAbility>();
+
+        combat_log_event_writer.send(CombatLogEvent::new(
+            &time,
+            cancel_cast_ability.source,
+            cast_ability.target,
+            CombatLogEventKind::CastStopped(cast_ability.ability.name.clone()),
+        ));
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn perform_ability_system(
     mut commands: Commands,
+    time: Res<Time>,
+    texture_atlases: Res<Assets<TextureAtlas>>,
     mut perform_ability_event_reader: EventReader<PerformAbility>,
     mut perform_effect_event_writer: EventWriter<PerformEffect>,
-    mut query: Query<(&Transform, &mut Mana, &mut AbilityCooldowns)>,
+    mut perform_ability_failed_event_writer: EventWriter<PerformAbilityFailed>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
+    mut query: Query<(&Transform, &Facing, &mut Mana, &mut AbilityCooldowns)>,
+    target_query: Query<(&Transform, &Health)>,
     creature_query: Query<(Entity, &Transform), With<Creature>>,
     obstacle_query: Query<&Transform, With<Obstacle>>,
 ) {
     for perform_ability in perform_ability_event_reader.iter() {
-        // TODO: Verify target position in case it moves while casting.
-
-        let (transform, mut mana, mut ability_cooldowns) =
+        let (transform, facing, mut mana, mut ability_cooldowns) =
             query.get_mut(perform_ability.source).unwrap();
 
+        let position = transform.translation.truncate();
+
+        // The target may have moved or died while casting.
+        if let Err(reason) =
+            verify_perform_target(perform_ability, position, &target_query, &obstacle_query)
+        {
+            perform_ability_failed_event_writer.send(PerformAbilityFailed {
+                source: perform_ability.source,
+                ability_id: perform_ability.ability.id,
+                reason,
+            });
+            combat_log_event_writer.send(CombatLogEvent::new(
+                &time,
+                perform_ability.source,
+                perform_ability.target,
+                CombatLogEventKind::CastFailed(perform_ability.ability.name.clone(), reason),
+            ));
+
+            continue;
+        }
+
         mana.points -= perform_ability.ability.mana_points;
 
         commands
             .entity(perform_ability.source)
             .insert(RegenManaCooldown::new());
 
-        if perform_ability.ability.cooldown_duration > 0.0 {
-            ability_cooldowns.push(perform_ability.ability);
+        if let Some(cooldown_duration) = perform_ability.ability.cooldown_duration {
+            ability_cooldowns.push(perform_ability.ability.id, cooldown_duration);
         }
 
-        let mut effects = vec![perform_ability.ability.effect];
-        if let Some(secondary_effect) = perform_ability.ability.secondary_effect {
-            effects.push(secondary_effect);
+        match perform_ability.ability.projectile {
+            Some(ability_projectile) => {
+                commands.spawn((
+                    SpriteSheetBundle {
+                        texture_atlas: texture_atlases.get_handle(Sprite::SHEET_PATH),
+                        sprite: TextureAtlasSprite::new(ability_projectile.sprite.index()),
+                        transform: Transform::from_translation(
+                            position.extend(Projectile::TRANSLATION_Z),
+                        ),
+                        ..default()
+                    },
+                    Projectile::new(
+                        perform_ability.source,
+                        perform_ability.target.unwrap(),
+                        perform_ability.ability.clone(),
+                    ),
+                ));
+            }
+            None => send_perform_effects(
+                perform_ability.source,
+                &perform_ability.ability,
+                perform_ability.target,
+                perform_ability.target_position,
+                position,
+                facing.direction,
+                &mut perform_effect_event_writer,
+                &creature_query,
+                &obstacle_query,
+            ),
         }
 
-        for (effect, effect_targeting) in effects.iter() {
-            let targets = match effect_targeting {
-                AbilityTargetMode::Single => vec![perform_ability.target.unwrap()],
-                AbilityTargetMode::Area => {
-                    let position = transform.translation.truncate();
-
-                    creature_query
-                        .iter()
-                        .filter(|(creature_entity, creature_transform)| {
-                            *creature_entity != perform_ability.source
-                                && verify_target_position(
-                                    position,
-                                    creature_transform.translation.truncate(),
-                                    perform_ability.ability.range,
-                                    &obstacle_query,
-                                )
-                                .is_ok()
-                        })
-                        .map(|(creature_entity, _)| creature_entity)
-                        .collect()
-                }
-            };
-
-            for target in &targets {
-                perform_effect_event_writer.send(PerformEffect {
-                    source: perform_ability.source,
-                    effect: *effect,
-                    target: *target,
-                });
+        combat_log_event_writer.send(CombatLogEvent::new(
+            &time,
+            perform_ability.source,
+            perform_ability.target,
+            CombatLogEventKind::CastSucceeded(perform_ability.ability.name.clone()),
+        ));
+    }
+}
+
+fn verify_perform_target(
+    perform_ability: &PerformAbility,
+    position: Vec2,
+    target_query: &Query<(&Transform, &Health)>,
+    obstacle_query: &Query<&Transform, With<Obstacle>>,
+) -> Result<(), AbilityFailedReason> {
+    if !perform_ability.ability.requires_target() {
+        return Ok(());
+    }
+
+    let target = match perform_ability.target {
+        Some(result) => result,
+        None => return Err(AbilityFailedReason::TargetDead),
+    };
+
+    let (target_transform, target_health) = match target_query.get(target) {
+        Ok(result) => result,
+        Err(_) => return Err(AbilityFailedReason::TargetDead),
+    };
+
+    if target_health.points == 0 {
+        return Err(AbilityFailedReason::TargetDead);
+    }
+
+    if target == perform_ability.source {
+        return Ok(());
+    }
+
+    verify_target_position(
+        position,
+        target_transform.translation.truncate(),
+        perform_ability.ability.range,
+        obstacle_query,
+    )?;
+
+    Ok(())
+}
+
+/// Sends the ability's effects to its target, and to other creatures overlapping shaped effects from the position.
+/// Shaped effects only reach creatures in line of sight of the shape's origin.
+#[allow(clippy::too_many_arguments)]
+pub fn send_perform_effects(
+    source: Entity,
+    ability: &Ability,
+    target: Option<Entity>,
+    target_position: Option<Vec2>,
+    position: Vec2,
+    direction: Vec2,
+    perform_effect_event_writer: &mut EventWriter<PerformEffect>,
+    creature_query: &Query<(Entity, &Transform), With<Creature>>,
+    obstacle_query: &Query<&Transform, With<Obstacle>>,
+) {
+    for (effect, target_mode) in ability.effects.iter() {
+        let targets = match target_mode {
+            AbilityTargetMode::Single => vec![target.unwrap()],
+            _ => {
+                let origin = match target_mode {
+                    AbilityTargetMode::Ground(_) => target_position.unwrap(),
+                    _ => position,
+                };
+
+                creature_query
+                    .iter()
+                    .filter(|(creature_entity, creature_transform)| {
+                        let creature_position = creature_transform.translation.truncate();
+
+                        *creature_entity != source
+                            && target_mode.overlaps(
+                                position,
+                                direction,
+                                target_position,
+                                ability.range,
+                                creature_position,
+                            )
+                            && verify_target_position(
+                                origin,
+                                creature_position,
+                                f32::INFINITY,
+                                obstacle_query,
+                            )
+                            .is_ok()
+                    })
+                    .map(|(creature_entity, _)| creature_entity)
+                    .collect()
             }
-        }
+        };
 
-        let ability_name = perform_ability.ability.name;
-        info!("Casted {ability_name}.");
+        for target in &targets {
+            perform_effect_event_writer.send(PerformEffect {
+                source,
+                effect: *effect,
+                target: *target,
+            });
+        }
     }
 }
 
-enum TargetPositionError {
+pub enum TargetPositionError {
     Range,
     Sight,
 }
 
-fn verify_target_position(
+pub fn verify_target_position(
     position: Vec2,
     target_position: Vec2,
     range: f32,
@@ -430,3 +760,267 @@ fn verify_target_position(
 
     Ok(())
 }
+
+#[test]
+fn test_ability_target_mode_overlaps() {
+    let origin = Vec2::ZERO;
+    let direction = Vec2::X;
+    let range = 64.0;
+    let overlaps = |target_mode: AbilityTargetMode, target_position, position| {
+        target_mode.overlaps(origin, direction, target_position, range, position)
+    };
+
+    assert!(overlaps(
+        AbilityTargetMode::Area,
+        None,
+        Vec2::new(0.0, -70.0)
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Area,
+        None,
+        Vec2::new(0.0, -80.0)
+    ));
+
+    let target_position = Some(Vec2::new(100.0, 100.0));
+    assert!(overlaps(
+        AbilityTargetMode::Ground(16.0),
+        target_position,
+        Vec2::new(120.0, 100.0)
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Ground(16.0),
+        target_position,
+        origin
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Ground(16.0),
+        None,
+        Vec2::new(100.0, 100.0)
+    ));
+
+    assert!(overlaps(
+        AbilityTargetMode::Cone(90.0),
+        None,
+        Vec2::new(40.0, 30.0)
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Cone(90.0),
+        None,
+        Vec2::new(30.0, 40.0)
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Cone(90.0),
+        None,
+        Vec2::new(-40.0, 0.0)
+    ));
+
+    assert!(overlaps(
+        AbilityTargetMode::Line(8.0),
+        None,
+        Vec2::new(60.0, 10.0)
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Line(8.0),
+        None,
+        Vec2::new(60.0, 20.0)
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Line(8.0),
+        None,
+        Vec2::new(-20.0, 0.0)
+    ));
+
+    assert!(overlaps(
+        AbilityTargetMode::Ring(32.0),
+        None,
+        Vec2::new(0.0, 50.0)
+    ));
+    assert!(!overlaps(
+        AbilityTargetMode::Ring(32.0),
+        None,
+        Vec2::new(0.0, 10.0)
+    ));
+}
+
+#[test]
+fn test_perform_ability_failed() {
+    use crate::effect::{MomentaryEffect, MomentaryEffectSchedule};
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(AssetPlugin::default())
+        .add_asset::<TextureAtlas>()
+        .add_event::<PerformAbility>()
+        .add_event::<PerformAbilityFailed>()
+        .add_event::<PerformEffect>()
+        .add_event::<CombatLogEvent>()
+        .add_system(perform_ability_system);
+
+    let entity = app
+        .world
+        .spawn((
+            Transform::default(),
+            Facing::default(),
+            Mana::new(100),
+            AbilityCooldowns::default(),
+            Health::new(100),
+        ))
+        .id();
+    let target_entity = app
+        .world
+        .spawn((Transform::from_xyz(100.0, 0.0, 0.0), Health::new(100)))
+        .id();
+
+    let perform_ability = |app: &mut App| {
+        app.world
+            .resource_mut::<Events<PerformAbilityFailed>>()
+            .clear();
+        app.world
+            .resource_mut::<Events<PerformAbility>>()
+            .send(PerformAbility {
+                source: entity,
+                ability: Ability {
+                    id: 0,
+                    name: "Fireball".to_string(),
+                    mana_points: 20,
+                    cast_duration: 2.5,
+                    cooldown_duration: None,
+                    range: 50.0,
+                    effects: vec![(
+                        Effect::Momentary(
+                            MomentaryEffect::Damage(1, 1),
+                            MomentaryEffectSchedule::Once,
+                        ),
+                        AbilityTargetMode::Single,
+                    )],
+                    projectile: None,
+                },
+                target: Some(target_entity),
+                target_position: None,
+            });
+        app.update();
+
+        let perform_ability_failed_events = app.world.resource::<Events<PerformAbilityFailed>>();
+        let mut perform_ability_failed_event_reader = perform_ability_failed_events.get_reader();
+        let reason = perform_ability_failed_event_reader
+            .iter(perform_ability_failed_events)
+            .map(|perform_ability_failed| perform_ability_failed.reason)
+            .next_back();
+
+        reason
+    };
+
+    // Target moved out of range while casting.
+    assert_eq!(
+        perform_ability(&mut app),
+        Some(AbilityFailedReason::OutOfRange)
+    );
+    assert_eq!(app.world.get::<Mana>(entity).unwrap().points, 100);
+
+    // Target died while casting.
+    app.world
+        .get_mut::<Transform>(target_entity)
+        .unwrap()
+        .translation
+        .x = 40.0;
+    app.world.get_mut::<Health>(target_entity).unwrap().points = 0;
+    assert_eq!(
+        perform_ability(&mut app),
+        Some(AbilityFailedReason::TargetDead)
+    );
+    assert_eq!(app.world.get::<Mana>(entity).unwrap().points, 100);
+
+    // Target still valid.
+    app.world.get_mut::<Health>(target_entity).unwrap().points = 100;
+    assert_eq!(perform_ability(&mut app), None);
+    assert_eq!(app.world.get::<Mana>(entity).unwrap().points, 80);
+}
+
+#[test]
+fn test_try_ability_failed() {
+    use crate::effect::{MomentaryEffect, MomentaryEffectSchedule};
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<TryAbility>()
+        .add_event::<TryAbilityFailed>()
+        .add_event::<PerformAbility>()
+        .add_event::<CombatLogEvent>()
+        .add_system(try_ability_system);
+
+    let entity = app
+        .world
+        .spawn((
+            Transform::default(),
+            Mana::new(10),
+            AbilityCooldowns::default(),
+            LastingEffects::default(),
+        ))
+        .id();
+    let target_entity = app.world.spawn(Transform::from_xyz(100.0, 0.0, 0.0)).id();
+
+    let try_ability = |app: &mut App, mana_points, target| {
+        app.world.resource_mut::<Events<TryAbilityFailed>>().clear();
+        app.world
+            .resource_mut::<Events<TryAbility>>()
+            .send(TryAbility {
+                source: entity,
+                ability: Ability {
+                    id: 0,
+                    name: "Fireball".to_string(),
+                    mana_points,
+                    cast_duration: 0.0,
+                    cooldown_duration: None,
+                    range: 50.0,
+                    effects: vec![(
+                        Effect::Momentary(
+                            MomentaryEffect::Damage(1, 1),
+                            MomentaryEffectSchedule::Once,
+                        ),
+                        AbilityTargetMode::Single,
+                    )],
+                    projectile: None,
+                },
+                target,
+                target_position: None,
+            });
+        app.update();
+
+        let try_ability_failed_events = app.world.resource::<Events<TryAbilityFailed>>();
+        let mut try_ability_failed_event_reader = try_ability_failed_events.get_reader();
+        let reason = try_ability_failed_event_reader
+            .iter(try_ability_failed_events)
+            .map(|try_ability_failed| {
+                assert_eq!(try_ability_failed.ability_id, 0);
+
+                try_ability_failed.reason
+            })
+            .next_back();
+
+        reason
+    };
+
+    assert_eq!(
+        try_ability(&mut app, 20, Some(target_entity)),
+        Some(AbilityFailedReason::NotEnoughMana)
+    );
+    assert_eq!(
+        try_ability(&mut app, 0, None),
+        Some(AbilityFailedReason::NoTarget)
+    );
+    assert_eq!(
+        try_ability(&mut app, 0, Some(target_entity)),
+        Some(AbilityFailedReason::OutOfRange)
+    );
+
+    app.world
+        .get_mut::<Transform>(target_entity)
+        .unwrap()
+        .translation
+        .x = 40.0;
+    assert_eq!(try_ability(&mut app, 0, Some(target_entity)), None);
+    assert_eq!(
+        try_ability(&mut app, 0, Some(target_entity)),
+        Some(AbilityFailedReason::GlobalCooldown)
+    );
+}
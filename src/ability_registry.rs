@@ -0,0 +1,313 @@
+use crate::ability::{Ability, AbilityTargetMode};
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::TypeUuid,
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+const ABILITY_DEFINITIONS_PATH: &str = "core.abilities.ron";
+
+/// Asset to store ability definitions, loaded from `.abilities.ron` files.
+#[derive(Deserialize, TypeUuid)]
+#[serde(transparent)]
+#[uuid = "5f0d2a3c-8e1b-4b6f-9d47-3c2a61e0b8f4"]
+pub struct AbilityDefinitions {
+    pub abilities: Vec<Ability>,
+}
+
+impl AbilityDefinitions {
+    /// Parses and validates ability definitions. Unknown effects and target modes are rejected while parsing.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, bevy::asset::Error> {
+        let ability_definitions: Self = ron::de::from_bytes(bytes)?;
+        ability_definitions.validate()?;
+
+        Ok(ability_definitions)
+    }
+
+    fn validate(&self) -> Result<(), AbilityDefinitionError> {
+        let mut ids = HashSet::new();
+        for ability in &self.abilities {
+            if !ids.insert(ability.id) {
+                return Err(AbilityDefinitionError::DuplicateId(ability.id));
+            }
+
+            if ability.effects.is_empty() {
+                return Err(AbilityDefinitionError::NoEffects(ability.id));
+            }
+
+            if ability.cast_duration < 0.0 {
+                return Err(AbilityDefinitionError::NegativeCastDuration(ability.id));
+            }
+
+            if matches!(ability.cooldown_duration, Some(cooldown_duration) if cooldown_duration <= 0.0)
+            {
+                return Err(AbilityDefinitionError::ZeroCooldown(ability.id));
+            }
+
+            if ability
+                .effects
+                .iter()
+                .any(|(_, target_mode)| match *target_mode {
+                    AbilityTargetMode::Single | AbilityTargetMode::Area => false,
+                    AbilityTargetMode::Ground(radius) => radius <= 0.0,
+                    AbilityTargetMode::Cone(angle) => angle <= 0.0 || angle > 360.0,
+                    AbilityTargetMode::Line(width) => width <= 0.0,
+                    AbilityTargetMode::Ring(inner_radius) => {
+                        inner_radius < 0.0 || inner_radius >= ability.range
+                    }
+                })
+            {
+                return Err(AbilityDefinitionError::InvalidShape(ability.id));
+            }
+
+            if let Some(ability_projectile) = ability.projectile {
+                // Effects land at the target on impact, so there is no target position for ground effects.
+                if ability_projectile.speed <= 0.0
+                    || !ability.requires_target()
+                    || ability.requires_target_position()
+                {
+                    return Err(AbilityDefinitionError::InvalidProjectile(ability.id));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AbilityDefinitionError {
+    DuplicateId(u8),
+    NoEffects(u8),
+    NegativeCastDuration(u8),
+    ZeroCooldown(u8),
+    InvalidProjectile(u8),
+    InvalidShape(u8),
+}
+
+impl fmt::Display for AbilityDefinitionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DuplicateId(id) => write!(formatter, "Ability ({id}) is defined more than once."),
+            Self::NoEffects(id) => write!(formatter, "Ability ({id}) has no effects."),
+            Self::NegativeCastDuration(id) => {
+                write!(formatter, "Ability ({id}) has a negative cast duration.")
+            }
+            Self::ZeroCooldown(id) => write!(
+                formatter,
+                "Ability ({id}) has a cooldown that is not positive, omit it instead."
+            ),
+            Self::InvalidProjectile(id) => write!(
+                formatter,
+                "Ability ({id}) has a projectile without a positive speed or a single target, or with a ground effect."
+            ),
+            Self::InvalidShape(id) => write!(
+                formatter,
+                "Ability ({id}) has a shape without a positive size, or a ring not smaller than its range."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AbilityDefinitionError {}
+
+#[derive(Default)]
+struct AbilityDefinitionsLoader;
+
+impl AssetLoader for AbilityDefinitionsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let ability_definitions = AbilityDefinitions::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(ability_definitions));
+
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["abilities.ron"]
+    }
+}
+
+/// Resource to look up abilities by identifier, kept in sync with the loaded ability definitions.
+#[derive(Resource, Default)]
+pub struct AbilityRegistry {
+    handle: Handle<AbilityDefinitions>,
+    abilities_by_id: HashMap<u8, Ability>,
+}
+
+impl AbilityRegistry {
+    pub fn get(&self, id: u8) -> Option<&Ability> {
+        self.abilities_by_id.get(&id)
+    }
+
+    pub fn set_abilities(&mut self, abilities: &[Ability]) {
+        self.abilities_by_id = abilities
+            .iter()
+            .map(|ability| (ability.id, ability.clone()))
+            .collect();
+    }
+}
+
+pub struct AbilityRegistryPlugin;
+
+impl Plugin for AbilityRegistryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<AbilityDefinitions>()
+            .init_asset_loader::<AbilityDefinitionsLoader>()
+            .init_resource::<AbilityRegistry>()
+            .add_startup_system(load_system)
+            .add_system(update_system);
+    }
+}
+
+fn load_system(asset_server: Res<AssetServer>, mut ability_registry: ResMut<AbilityRegistry>) {
+    ability_registry.handle = asset_server.load(ABILITY_DEFINITIONS_PATH);
+}
+
+fn update_system(
+    mut asset_event_reader: EventReader<AssetEvent<AbilityDefinitions>>,
+    ability_definitions_assets: Res<Assets<AbilityDefinitions>>,
+    mut ability_registry: ResMut<AbilityRegistry>,
+) {
+    for asset_event in asset_event_reader.iter() {
+        let handle = match asset_event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => handle,
+            AssetEvent::Removed { .. } => continue,
+        };
+
+        if *handle != ability_registry.handle {
+            continue;
+        }
+
+        let ability_definitions = match ability_definitions_assets.get(handle) {
+            Some(result) => result,
+            None => continue,
+        };
+
+        ability_registry.set_abilities(&ability_definitions.abilities);
+
+        let count = ability_registry.abilities_by_id.len();
+        info!("Loaded {count} abilities.");
+    }
+}
+
+#[test]
+fn test_ability_definitions_valid() {
+    let ability_definitions =
+        AbilityDefinitions::from_bytes(include_bytes!("../assets/core.abilities.ron")).unwrap();
+
+    assert_eq!(ability_definitions.abilities.len(), 9);
+}
+
+#[test]
+fn test_ability_definitions_invalid() {
+    assert!(AbilityDefinitions::from_bytes(
+        br#"[
+            (
+                id: 0,
+                name: "Frostbolt",
+                mana_points: 20,
+                range: 200.0,
+                effects: [(Momentary(Freeze(30, 50), Once), Single)],
+            ),
+        ]"#,
+    )
+    .is_err());
+
+    let ability_definitions: AbilityDefinitions = ron::de::from_str(
+        r#"[
+            (id: 0, name: "A", mana_points: 0, range: 0.0, effects: [(Lasting(Silence, 1.0), Single)]),
+            (id: 0, name: "B", mana_points: 0, range: 0.0, effects: [(Lasting(Silence, 1.0), Single)]),
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ability_definitions.validate(),
+        Err(AbilityDefinitionError::DuplicateId(0))
+    );
+
+    let ability_definitions: AbilityDefinitions = ron::de::from_str(
+        r#"[
+            (
+                id: 0,
+                name: "A",
+                mana_points: 0,
+                cooldown_duration: Some(0.0),
+                range: 0.0,
+                effects: [(Lasting(Silence, 1.0), Single)],
+            ),
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ability_definitions.validate(),
+        Err(AbilityDefinitionError::ZeroCooldown(0))
+    );
+
+    let ability_definitions: AbilityDefinitions = ron::de::from_str(
+        r#"[
+            (
+                id: 0,
+                name: "A",
+                mana_points: 0,
+                range: 0.0,
+                effects: [(Momentary(Damage(1, 1), Once), Area)],
+                projectile: Some((speed: 100.0, sprite: Fireball)),
+            ),
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ability_definitions.validate(),
+        Err(AbilityDefinitionError::InvalidProjectile(0))
+    );
+
+    let ability_definitions: AbilityDefinitions = ron::de::from_str(
+        r#"[
+            (
+                id: 0,
+                name: "A",
+                mana_points: 0,
+                range: 50.0,
+                effects: [
+                    (Momentary(Damage(1, 1), Once), Single),
+                    (Momentary(Damage(1, 1), Once), Ground(24.0)),
+                ],
+                projectile: Some((speed: 100.0, sprite: Fireball)),
+            ),
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ability_definitions.validate(),
+        Err(AbilityDefinitionError::InvalidProjectile(0))
+    );
+
+    let ability_definitions: AbilityDefinitions = ron::de::from_str(
+        r#"[
+            (
+                id: 0,
+                name: "A",
+                mana_points: 0,
+                range: 50.0,
+                effects: [(Momentary(Damage(1, 1), Once), Ring(60.0))],
+            ),
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ability_definitions.validate(),
+        Err(AbilityDefinitionError::InvalidShape(0))
+    );
+}
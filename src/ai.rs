@@ -0,0 +1,369 @@
+use crate::{
+    ability::{
+        verify_target_position, AbilityCooldowns, AbilityGlobalCooldown, CastAbility, TryAbility,
+    },
+    ability_registry::AbilityRegistry,
+    effect::LastingEffects,
+    enemy::Enemy,
+    health::Health,
+    level::Obstacle,
+    mana::Mana,
+    navigation::{MoveTo, Path},
+    player::Player,
+    position::ChangePosition,
+    target::Target,
+    threat::{taunting_entity, ThreatTable},
+    zone::Tile,
+    AppState,
+};
+use bevy::prelude::*;
+
+/// Settings for how a kind of creature fights.
+#[derive(Clone)]
+pub struct AiConfig {
+    /// Distance within which hostile creatures are noticed.
+    pub aggro_range: f32,
+    /// Distance to close in to before using abilities.
+    pub preferred_range: f32,
+    /// Fraction of health below which the creature moves away from its target.
+    pub retreat_health_fraction: f32,
+    /// Abilities to try, in order of priority.
+    pub ability_ids: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiState {
+    Idle,
+    Approach,
+    Attack,
+    Retreat,
+}
+
+/// Component to make a creature act on its own.
+#[derive(Component)]
+pub struct Ai {
+    pub state: AiState,
+    config: AiConfig,
+}
+
+impl Ai {
+    pub fn new(config: AiConfig) -> Self {
+        Self {
+            state: AiState::Idle,
+            config,
+        }
+    }
+}
+
+pub struct AiPlugin;
+
+impl Plugin for AiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(AppState::Game)
+                .with_system(behavior_system)
+                .with_system(stop_path_system.after(behavior_system)),
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn behavior_system(
+    mut commands: Commands,
+    ability_registry: Res<AbilityRegistry>,
+    mut change_position_event_writer: EventWriter<ChangePosition>,
+    mut move_to_event_writer: EventWriter<MoveTo>,
+    mut try_ability_event_writer: EventWriter<TryAbility>,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &Health,
+            &Mana,
+            &AbilityCooldowns,
+            &LastingEffects,
+            &mut Target,
+            &mut Ai,
+            Option<&mut ThreatTable>,
+            Option<&CastAbility>,
+            Option<&AbilityGlobalCooldown>,
+            Option<&Path>,
+        ),
+        With<Enemy>,
+    >,
+    hostile_query: Query<(Entity, &Transform, &Health), (With<Player>, Without<Enemy>)>,
+    obstacle_query: Query<&Transform, With<Obstacle>>,
+) {
+    for (
+        entity,
+        transform,
+        health,
+        mana,
+        ability_cooldowns,
+        lasting_effects,
+        mut target,
+        mut ai,
+        mut threat_table,
+        cast_ability,
+        ability_global_cooldown,
+        path,
+    ) in query.iter_mut()
+    {
+        if health.points == 0 {
+            ai.state = AiState::Idle;
+
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+
+        let is_alive = |hostile_entity: Entity| match hostile_query.get(hostile_entity) {
+            Ok((_, _, hostile_health)) => hostile_health.points > 0,
+            Err(_) => false,
+        };
+
+        // A taunt comes first, then the highest threat, then the current target while it lives, then the nearest one in range.
+        let threat_entity = taunting_entity(lasting_effects)
+            .filter(|taunting_entity| is_alive(*taunting_entity))
+            .or_else(|| {
+                threat_table.as_ref().and_then(|threat_table| {
+                    threat_table
+                        .ranked()
+                        .into_iter()
+                        .map(|(threat_entity, _)| threat_entity)
+                        .find(|threat_entity| is_alive(*threat_entity))
+                })
+            });
+        if threat_entity.is_some() {
+            target.entity = threat_entity;
+        } else if !matches!(target.entity, Some(target_entity) if is_alive(target_entity)) {
+            target.entity = hostile_query
+                .iter()
+                .filter(|(_, hostile_transform, hostile_health)| {
+                    hostile_health.points > 0
+                        && hostile_transform.translation.truncate().distance(position)
+                            <= ai.config.aggro_range
+                })
+                .min_by(|(_, transform, _), (_, other_transform, _)| {
+                    let distance = transform.translation.truncate().distance(position);
+                    let other_distance = other_transform.translation.truncate().distance(position);
+
+                    distance.total_cmp(&other_distance)
+                })
+                .map(|(hostile_entity, _, _)| hostile_entity);
+
+            if let (Some(target_entity), Some(threat_table)) = (target.entity, &mut threat_table) {
+                threat_table.engage(target_entity);
+            }
+        }
+
+        let target_entity = match target.entity {
+            Some(result) => result,
+            None => {
+                ai.state = AiState::Idle;
+
+                continue;
+            }
+        };
+
+        let (_, target_transform, _) = hostile_query.get(target_entity).unwrap();
+        let target_position = target_transform.translation.truncate();
+        let offset = target_position - position;
+        let distance = offset.length();
+
+        let health_fraction = health.points as f32 / health.max_points as f32;
+        if health_fraction < ai.config.retreat_health_fraction {
+            ai.state = AiState::Retreat;
+
+            if distance < ai.config.aggro_range && offset != Vec2::ZERO {
+                change_position_event_writer.send(ChangePosition {
+                    entity,
+                    direction: -offset.normalize(),
+                });
+            }
+
+            continue;
+        }
+
+        if cast_ability.is_some() {
+            ai.state = AiState::Attack;
+
+            continue;
+        }
+
+        let ability = ai
+            .config
+            .ability_ids
+            .iter()
+            .filter_map(|ability_id| ability_registry.get(*ability_id))
+            .find(|ability| {
+                !ability_cooldowns.contains(ability.id)
+                    && ability.mana_points <= mana.points
+                    && verify_target_position(
+                        position,
+                        target_position,
+                        ability.range,
+                        &obstacle_query,
+                    )
+                    .is_ok()
+            });
+
+        if distance > ai.config.preferred_range || ability.is_none() {
+            ai.state = AiState::Approach;
+
+            let is_in_sight =
+                verify_target_position(position, target_position, f32::INFINITY, &obstacle_query)
+                    .is_ok();
+            if !is_in_sight {
+                // Find a way around obstacles, planning again once the target moves away from the destination.
+                let is_path_outdated = match path {
+                    Some(path) => path.destination.distance(target_position) > Tile::SIZE,
+                    None => true,
+                };
+                if is_path_outdated {
+                    move_to_event_writer.send(MoveTo {
+                        entity,
+                        destination: target_position,
+                    });
+                }
+            } else if distance > ai.config.preferred_range {
+                if path.is_some() {
+                    commands.entity(entity).remove::<Path>();
+                }
+
+                change_position_event_writer.send(ChangePosition {
+                    entity,
+                    direction: offset.normalize(),
+                });
+            }
+
+            continue;
+        }
+
+        ai.state = AiState::Attack;
+
+        if ability_global_cooldown.is_none() {
+            try_ability_event_writer.send(TryAbility {
+                source: entity,
+                ability: ability.unwrap().clone(),
+                target: Some(target_entity),
+                target_position: None,
+            });
+        }
+    }
+}
+
+/// Stops following paths once creatures stop approaching their target.
+fn stop_path_system(mut commands: Commands, query: Query<(Entity, &Ai), With<Path>>) {
+    for (entity, ai) in query.iter() {
+        if ai.state != AiState::Approach {
+            commands.entity(entity).remove::<Path>();
+        }
+    }
+}
+
+#[test]
+fn test_ai_behavior() {
+    use crate::{
+        ability::{Ability, AbilityTargetMode},
+        effect::{Effect, MomentaryEffect, MomentaryEffectSchedule},
+    };
+
+    let mut ability_registry = AbilityRegistry::default();
+    ability_registry.set_abilities(&[Ability {
+        id: 0,
+        name: "Stab".to_string(),
+        mana_points: 0,
+        cast_duration: 0.0,
+        cooldown_duration: None,
+        range: 24.0,
+        effects: vec![(
+            Effect::Momentary(MomentaryEffect::Damage(1, 1), MomentaryEffectSchedule::Once),
+            AbilityTargetMode::Single,
+        )],
+        projectile: None,
+    }]);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(ability_registry)
+        .add_event::<ChangePosition>()
+        .add_event::<MoveTo>()
+        .add_event::<TryAbility>()
+        .add_system(behavior_system);
+
+    let player_entity = app
+        .world
+        .spawn((Player, Health::new(100), Transform::from_xyz(0.0, 0.0, 0.0)))
+        .id();
+    let entity = app
+        .world
+        .spawn((
+            Enemy,
+            Health::new(100),
+            Mana::new(100),
+            AbilityCooldowns::default(),
+            LastingEffects::default(),
+            Target::default(),
+            Ai::new(AiConfig {
+                aggro_range: 200.0,
+                preferred_range: 20.0,
+                retreat_health_fraction: 0.25,
+                ability_ids: vec![0],
+            }),
+            Transform::from_xyz(100.0, 0.0, 0.0),
+        ))
+        .id();
+
+    let last_change_position_direction = |app: &App| {
+        let change_position_events = app.world.resource::<Events<ChangePosition>>();
+        let mut change_position_event_reader = change_position_events.get_reader();
+        let direction = change_position_event_reader
+            .iter(change_position_events)
+            .rfind(|change_position| change_position.entity == entity)
+            .map(|change_position| change_position.direction);
+
+        direction
+    };
+
+    // Out of range, approach.
+    app.update();
+    assert_eq!(
+        app.world.get::<Ai>(entity).unwrap().state,
+        AiState::Approach
+    );
+    assert_eq!(
+        app.world.get::<Target>(entity).unwrap().entity,
+        Some(player_entity)
+    );
+    assert_eq!(last_change_position_direction(&app), Some(-Vec2::X));
+
+    // In range, attack.
+    app.world
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation
+        .x = 16.0;
+    app.update();
+    assert_eq!(app.world.get::<Ai>(entity).unwrap().state, AiState::Attack);
+    let try_ability_events = app.world.resource::<Events<TryAbility>>();
+    let mut try_ability_event_reader = try_ability_events.get_reader();
+    let try_ability = try_ability_event_reader
+        .iter(try_ability_events)
+        .last()
+        .unwrap();
+    assert_eq!(try_ability.source, entity);
+    assert_eq!(try_ability.target, Some(player_entity));
+
+    // Low health, retreat.
+    app.world.get_mut::<Health>(entity).unwrap().points = 10;
+    app.update();
+    assert_eq!(app.world.get::<Ai>(entity).unwrap().state, AiState::Retreat);
+    assert_eq!(last_change_position_direction(&app), Some(Vec2::X));
+
+    // Target dead, idle.
+    app.world.get_mut::<Health>(player_entity).unwrap().points = 0;
+    app.update();
+    assert_eq!(app.world.get::<Ai>(entity).unwrap().state, AiState::Idle);
+    assert_eq!(app.world.get::<Target>(entity).unwrap().entity, None);
+}
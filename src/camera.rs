@@ -0,0 +1,237 @@
+use crate::{
+    effect::{MomentaryEffectPerformed, PerformedMomentaryEffect},
+    interface::easing::{ease, Easing},
+    player::Player,
+    zone::{Tile, Zone},
+    AppState,
+};
+use bevy::{prelude::*, window::WindowResized};
+use rand::prelude::*;
+
+/// Minimum size of the world area that should be visible, scaled up by whole numbers to fill the window.
+const VIEWPORT_MIN_SIZE: Vec2 = Vec2::new(400.0, 300.0);
+
+const FOLLOW_DURATION: f32 = 0.5;
+const SHAKE_DURATION: f32 = 0.25;
+const SHAKE_AMPLITUDE: f32 = 2.0;
+
+/// Resource to configure how the camera follows the player and shakes.
+#[derive(Resource)]
+pub struct CameraSettings {
+    /// Duration to catch up with the player, shaped by the easing.
+    pub follow_duration: f32,
+    pub follow_easing: Easing,
+    pub shake_duration: f32,
+    pub shake_amplitude: f32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            follow_duration: FOLLOW_DURATION,
+            follow_easing: Easing::OutQuart,
+            shake_duration: SHAKE_DURATION,
+            shake_amplitude: SHAKE_AMPLITUDE,
+        }
+    }
+}
+
+/// Resource to store the visible world area and how many physical pixels a world unit spans.
+#[derive(Resource)]
+pub struct Viewport {
+    pub size: Vec2,
+    pub pixel_scale: f32,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            size: VIEWPORT_MIN_SIZE,
+            pixel_scale: 1.0,
+        }
+    }
+}
+
+/// Component to store the camera's followed position, before shake is applied. Snaps to the player when `None`.
+#[derive(Component, Default)]
+struct CameraFollow {
+    position: Option<Vec2>,
+}
+
+/// Component to shake the camera for a duration.
+#[derive(Component)]
+struct CameraShake {
+    duration_timer: Timer,
+}
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraSettings>()
+            .init_resource::<Viewport>()
+            .add_startup_system(spawn_system)
+            .add_system_to_stage(CoreStage::PreUpdate, scale_system)
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(follow_system)
+                    .with_system(start_shake_system)
+                    .with_system(shake_system.after(follow_system)),
+            )
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_system));
+    }
+}
+
+fn spawn_system(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), CameraFollow::default()));
+}
+
+/// Scales the camera by the largest whole number of physical pixels per world unit that fits the minimum viewport.
+/// Runs until the primary window is first scaled, since a resize event isn't guaranteed at startup, then on resizes.
+fn scale_system(
+    mut is_scaled: Local<bool>,
+    mut window_resized_event_reader: EventReader<WindowResized>,
+    windows: Res<Windows>,
+    mut viewport: ResMut<Viewport>,
+    mut query: Query<&mut OrthographicProjection, With<CameraFollow>>,
+) {
+    let is_resized = window_resized_event_reader
+        .iter()
+        .any(|window_resized| window_resized.id.is_primary());
+    if *is_scaled && !is_resized {
+        return;
+    }
+
+    let window = match windows.get_primary() {
+        Some(result) => result,
+        None => return,
+    };
+
+    let physical_size = Vec2::new(
+        window.physical_width() as f32,
+        window.physical_height() as f32,
+    );
+    let pixel_scale = (physical_size / VIEWPORT_MIN_SIZE)
+        .min_element()
+        .floor()
+        .max(1.0);
+
+    for mut projection in query.iter_mut() {
+        projection.scale = window.scale_factor() as f32 / pixel_scale;
+        *is_scaled = true;
+    }
+
+    viewport.size = physical_size / pixel_scale;
+    viewport.pixel_scale = pixel_scale;
+}
+
+fn follow_system(
+    time: Res<Time>,
+    camera_settings: Res<CameraSettings>,
+    viewport: Res<Viewport>,
+    zone: Option<Res<Zone>>,
+    mut camera_query: Query<(&mut Transform, &mut CameraFollow), Without<Player>>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let player_position = match player_query.get_single() {
+        Ok(result) => result.translation.truncate(),
+        Err(_) => return,
+    };
+
+    let (mut transform, mut camera_follow) = camera_query.single_mut();
+
+    let mut position = match camera_follow.position {
+        Some(position) => {
+            let progress = (time.delta_seconds() / camera_settings.follow_duration).min(1.0);
+
+            position + (player_position - position) * ease(progress, camera_settings.follow_easing)
+        }
+        None => player_position,
+    };
+
+    if let Some(zone) = zone {
+        let min_position = zone.origin() - Vec2::splat(Tile::SIZE / 2.0);
+        let max_position = min_position + Vec2::new(zone.width(), zone.height());
+        let half_size = viewport.size / 2.0;
+
+        position.x = clamp_axis(position.x, min_position.x, max_position.x, half_size.x);
+        position.y = clamp_axis(position.y, min_position.y, max_position.y, half_size.y);
+    }
+
+    camera_follow.position = Some(position);
+
+    // Snap to physical pixels to keep nearest-filtered sprites crisp.
+    let snapped_position = (position * viewport.pixel_scale).round() / viewport.pixel_scale;
+    transform.translation.x = snapped_position.x;
+    transform.translation.y = snapped_position.y;
+}
+
+/// Clamps the value so that the extent around it stays within bounds, centering if the bounds are smaller.
+fn clamp_axis(value: f32, min_value: f32, max_value: f32, half_extent: f32) -> f32 {
+    if max_value - min_value <= half_extent * 2.0 {
+        return (min_value + max_value) / 2.0;
+    }
+
+    value.clamp(min_value + half_extent, max_value - half_extent)
+}
+
+fn start_shake_system(
+    mut commands: Commands,
+    camera_settings: Res<CameraSettings>,
+    mut momentary_effect_performed_event_reader: EventReader<MomentaryEffectPerformed>,
+    camera_query: Query<Entity, With<CameraFollow>>,
+) {
+    let is_critical =
+        momentary_effect_performed_event_reader
+            .iter()
+            .any(|momentary_effect_performed| {
+                matches!(
+                    momentary_effect_performed.performed_momentary_effect,
+                    PerformedMomentaryEffect::Damage(_, true)
+                )
+            });
+
+    if is_critical {
+        commands.entity(camera_query.single()).insert(CameraShake {
+            duration_timer: Timer::from_seconds(camera_settings.shake_duration, TimerMode::Once),
+        });
+    }
+}
+
+fn shake_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    camera_settings: Res<CameraSettings>,
+    mut query: Query<(Entity, &mut Transform, &mut CameraShake)>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for (entity, mut transform, mut camera_shake) in query.iter_mut() {
+        camera_shake.duration_timer.tick(time.delta());
+
+        if camera_shake.duration_timer.finished() {
+            commands.entity(entity).remove::<CameraShake>();
+
+            continue;
+        }
+
+        let amplitude =
+            camera_settings.shake_amplitude * camera_shake.duration_timer.percent_left();
+        let offset = Vec2::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0)) * amplitude;
+
+        transform.translation += offset.round().extend(0.0);
+    }
+}
+
+fn reset_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut CameraFollow)>,
+) {
+    for (entity, mut transform, mut camera_follow) in query.iter_mut() {
+        camera_follow.position = None;
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+
+        commands.entity(entity).remove::<CameraShake>();
+    }
+}
@@ -0,0 +1,59 @@
+use crate::ability::AbilityFailedReason;
+use bevy::prelude::*;
+
+/// Event to record something that happened in combat, for the combat log and balancing.
+#[derive(Clone)]
+pub struct CombatLogEvent {
+    /// Seconds since startup.
+    pub time: f32,
+    pub source: Entity,
+    pub target: Option<Entity>,
+    pub kind: CombatLogEventKind,
+}
+
+impl CombatLogEvent {
+    pub fn new(
+        time: &Time,
+        source: Entity,
+        target: Option<Entity>,
+        kind: CombatLogEventKind,
+    ) -> Self {
+        Self {
+            time: time.elapsed_seconds(),
+            source,
+            target,
+            kind,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CombatLogEventKind {
+    /// Started casting the named ability.
+    CastStarted(String),
+    /// Performed the named ability.
+    CastSucceeded(String),
+    /// Stopped casting the named ability by cancelling.
+    CastStopped(String),
+    /// Casting the named ability was interrupted by moving.
+    CastInterrupted(String),
+    /// The named ability failed once its cast was over.
+    CastFailed(String, AbilityFailedReason),
+    /// Damage points, and whether the hit was critical.
+    Damage(u16, bool),
+    /// Heal points, and whether the heal was critical.
+    Heal(u16, bool),
+    AuraApplied(&'static str),
+    /// Aura cleared before running out, such as when its bearer died.
+    AuraRemoved(&'static str),
+    AuraExpired(&'static str),
+    Died,
+}
+
+pub struct CombatLogPlugin;
+
+impl Plugin for CombatLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CombatLogEvent>();
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     effect::{LastingEffects, PeriodicMomentaryEffects},
     health::Health,
     mana::Mana,
+    position::Facing,
     target::Target,
 };
 use bevy::prelude::*;
@@ -23,6 +24,7 @@ pub struct CreatureBundle {
     lasting_effects: LastingEffects,
     ability_cooldowns: AbilityCooldowns,
     target: Target,
+    facing: Facing,
 }
 
 impl CreatureBundle {
@@ -36,6 +38,7 @@ impl CreatureBundle {
             lasting_effects: LastingEffects::default(),
             ability_cooldowns: AbilityCooldowns::default(),
             target: Target::default(),
+            facing: Facing::default(),
         }
     }
 }
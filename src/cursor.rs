@@ -0,0 +1,39 @@
+use bevy::{prelude::*, render::camera::RenderTarget};
+
+/// Resource to store the cursor position in world space, `None` while the cursor is outside the window.
+#[derive(Resource, Default)]
+pub struct WorldCursor {
+    pub position: Option<Vec2>,
+}
+
+pub struct CursorPlugin;
+
+impl Plugin for CursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldCursor>()
+            .add_system_to_stage(CoreStage::PreUpdate, update_system);
+    }
+}
+
+fn update_system(
+    windows: Res<Windows>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut world_cursor: ResMut<WorldCursor>,
+) {
+    world_cursor.position = match camera_query.get_single() {
+        Ok((camera, camera_transform)) => {
+            let window = match camera.target {
+                RenderTarget::Window(window_id) => windows.get(window_id),
+                RenderTarget::Image(_) => None,
+            };
+
+            window
+                .and_then(|window| window.cursor_position())
+                .and_then(|cursor_position| {
+                    camera.viewport_to_world(camera_transform, cursor_position)
+                })
+                .map(|ray| ray.origin.truncate())
+        }
+        Err(_) => None,
+    };
+}
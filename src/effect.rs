@@ -1,32 +1,55 @@
 use crate::{
+    combat_log::{CombatLogEvent, CombatLogEventKind},
     critical::{Critical, CRITICAL_MULTIPLIER},
     health::Health,
     AppState,
 };
 use bevy::prelude::*;
 use rand::prelude::*;
+use serde::Deserialize;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Deserialize)]
 pub enum Effect {
     Momentary(MomentaryEffect, MomentaryEffectSchedule),
     Lasting(LastingEffect, f32),
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Deserialize)]
 pub enum MomentaryEffect {
     Damage(u16, u16),
     Heal(u16, u16),
 }
 
-#[derive(Clone, Copy)]
+impl MomentaryEffect {
+    /// Returns the name of the effect when it is performed periodically.
+    pub fn periodic_name(&self) -> &'static str {
+        match self {
+            Self::Damage(_, _) => "Damage over time",
+            Self::Heal(_, _) => "Heal over time",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Deserialize)]
 pub enum MomentaryEffectSchedule {
     Once,
     Periodic(f32, f32),
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Deserialize)]
 pub enum LastingEffect {
     Silence,
+    /// Forces the target to attack the source.
+    Taunt,
+}
+
+impl LastingEffect {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Silence => "Silence",
+            Self::Taunt => "Taunt",
+        }
+    }
 }
 
 /// Event to perform an effect, usually when an ability is cast.
@@ -43,6 +66,7 @@ pub enum PerformedMomentaryEffect {
 
 /// Event to communicate performing momentary effect on an entity.
 pub struct MomentaryEffectPerformed {
+    pub source: Entity,
     pub entity: Entity,
     pub performed_momentary_effect: PerformedMomentaryEffect,
 }
@@ -57,14 +81,14 @@ struct PerformMomentaryEffect {
 /// Component to store ongoing periodic momentary effects.
 #[derive(Component, Default)]
 pub struct PeriodicMomentaryEffects {
-    instances: Vec<PeriodicMomentaryEffectInstance>,
+    pub instances: Vec<PeriodicMomentaryEffectInstance>,
 }
 
-struct PeriodicMomentaryEffectInstance {
-    effect: MomentaryEffect,
-    interval_timer: Timer,
-    duration_timer: Timer,
-    source: Entity,
+pub struct PeriodicMomentaryEffectInstance {
+    pub effect: MomentaryEffect,
+    pub interval_timer: Timer,
+    pub duration_timer: Timer,
+    pub source: Entity,
 }
 
 /// Component to store ongoing lasting effects.
@@ -91,14 +115,17 @@ impl Plugin for EffectPlugin {
                     .with_system(perform_effect_system)
                     .with_system(perform_momentary_effect_system)
                     .with_system(tick_periodic_momentary_effects_system)
-                    .with_system(tick_lasting_effects_system),
+                    .with_system(tick_lasting_effects_system)
+                    .with_system(remove_dead_effects_system),
             );
     }
 }
 
 fn perform_effect_system(
+    time: Res<Time>,
     mut perform_effect_event_reader: EventReader<PerformEffect>,
     mut perform_momentary_effect_event_writer: EventWriter<PerformMomentaryEffect>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
     mut periodic_momentary_effects_query: Query<&mut PeriodicMomentaryEffects>,
     mut lasting_effects_query: Query<&mut LastingEffects>,
 ) {
@@ -122,7 +149,14 @@ fn perform_effect_system(
                         interval_timer: Timer::from_seconds(interval, TimerMode::Repeating),
                         duration_timer: Timer::from_seconds(duration, TimerMode::Once),
                         source: perform_effect.source,
-                    })
+                    });
+
+                combat_log_event_writer.send(CombatLogEvent::new(
+                    &time,
+                    perform_effect.source,
+                    Some(perform_effect.target),
+                    CombatLogEventKind::AuraApplied(effect.periodic_name()),
+                ));
             }
             Effect::Lasting(effect, duration) => {
                 let mut lasting_effects = lasting_effects_query
@@ -132,15 +166,24 @@ fn perform_effect_system(
                     effect,
                     duration_timer: Timer::from_seconds(duration, TimerMode::Once),
                     source: perform_effect.source,
-                })
+                });
+
+                combat_log_event_writer.send(CombatLogEvent::new(
+                    &time,
+                    perform_effect.source,
+                    Some(perform_effect.target),
+                    CombatLogEventKind::AuraApplied(effect.name()),
+                ));
             }
         }
     }
 }
 
 fn perform_momentary_effect_system(
+    time: Res<Time>,
     mut perform_momentary_effect_event_reader: EventReader<PerformMomentaryEffect>,
     mut momentary_effect_performed_event_writer: EventWriter<MomentaryEffectPerformed>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
     mut critical_query: Query<Option<&Critical>>,
     mut health_query: Query<&mut Health>,
 ) {
@@ -166,6 +209,7 @@ fn perform_momentary_effect_system(
                     points *= CRITICAL_MULTIPLIER;
                 }
 
+                let was_alive = health.points > 0;
                 if health.points > points {
                     health.points -= points;
                 } else {
@@ -175,7 +219,23 @@ fn perform_momentary_effect_system(
                     info!("{target:?} died.");
                 }
 
+                combat_log_event_writer.send(CombatLogEvent::new(
+                    &time,
+                    perform_momentary_effect.source,
+                    Some(target),
+                    CombatLogEventKind::Damage(points, is_critical),
+                ));
+                if was_alive && health.points == 0 {
+                    combat_log_event_writer.send(CombatLogEvent::new(
+                        &time,
+                        perform_momentary_effect.source,
+                        Some(target),
+                        CombatLogEventKind::Died,
+                    ));
+                }
+
                 momentary_effect_performed_event_writer.send(MomentaryEffectPerformed {
+                    source: perform_momentary_effect.source,
                     entity: target,
                     performed_momentary_effect: PerformedMomentaryEffect::Damage(
                         points,
@@ -201,7 +261,15 @@ fn perform_momentary_effect_system(
 
                 health.points = (health.points + points).min(health.max_points);
 
+                combat_log_event_writer.send(CombatLogEvent::new(
+                    &time,
+                    perform_momentary_effect.source,
+                    Some(target),
+                    CombatLogEventKind::Heal(points, is_critical),
+                ));
+
                 momentary_effect_performed_event_writer.send(MomentaryEffectPerformed {
+                    source: perform_momentary_effect.source,
                     entity: target,
                     performed_momentary_effect: PerformedMomentaryEffect::Heal(points, is_critical),
                 });
@@ -213,6 +281,7 @@ fn perform_momentary_effect_system(
 fn tick_periodic_momentary_effects_system(
     time: Res<Time>,
     mut perform_momentary_effect_event_writer: EventWriter<PerformMomentaryEffect>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
     mut query: Query<(Entity, &mut PeriodicMomentaryEffects)>,
 ) {
     for (entity, mut periodic_momentary_effects) in query.iter_mut() {
@@ -228,19 +297,163 @@ fn tick_periodic_momentary_effects_system(
             }
 
             instance.duration_timer.tick(time.delta());
+            if instance.duration_timer.finished() {
+                combat_log_event_writer.send(CombatLogEvent::new(
+                    &time,
+                    instance.source,
+                    Some(entity),
+                    CombatLogEventKind::AuraExpired(instance.effect.periodic_name()),
+                ));
+            }
         }
 
         instances.retain(|instance| !instance.duration_timer.finished());
     }
 }
 
-fn tick_lasting_effects_system(time: Res<Time>, mut query: Query<&mut LastingEffects>) {
-    for mut lasting_effects in query.iter_mut() {
+fn tick_lasting_effects_system(
+    time: Res<Time>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
+    mut query: Query<(Entity, &mut LastingEffects)>,
+) {
+    for (entity, mut lasting_effects) in query.iter_mut() {
         let instances = &mut lasting_effects.instances;
         for instance in instances.iter_mut() {
             instance.duration_timer.tick(time.delta());
+            if instance.duration_timer.finished() {
+                combat_log_event_writer.send(CombatLogEvent::new(
+                    &time,
+                    instance.source,
+                    Some(entity),
+                    CombatLogEventKind::AuraExpired(instance.effect.name()),
+                ));
+            }
         }
 
         instances.retain(|instance| !instance.duration_timer.finished());
     }
 }
+
+/// Clears the effects of dead creatures.
+fn remove_dead_effects_system(
+    time: Res<Time>,
+    mut combat_log_event_writer: EventWriter<CombatLogEvent>,
+    mut query: Query<(
+        Entity,
+        &Health,
+        &mut PeriodicMomentaryEffects,
+        &mut LastingEffects,
+    )>,
+) {
+    for (entity, health, mut periodic_momentary_effects, mut lasting_effects) in query.iter_mut() {
+        if health.points > 0
+            || (periodic_momentary_effects.instances.is_empty()
+                && lasting_effects.instances.is_empty())
+        {
+            continue;
+        }
+
+        let periodic_auras = periodic_momentary_effects
+            .instances
+            .drain(..)
+            .map(|instance| (instance.source, instance.effect.periodic_name()));
+        let lasting_auras = lasting_effects
+            .instances
+            .drain(..)
+            .map(|instance| (instance.source, instance.effect.name()));
+        for (source, name) in periodic_auras.chain(lasting_auras) {
+            combat_log_event_writer.send(CombatLogEvent::new(
+                &time,
+                source,
+                Some(entity),
+                CombatLogEventKind::AuraRemoved(name),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_combat_log_events() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<PerformEffect>()
+        .add_event::<PerformMomentaryEffect>()
+        .add_event::<MomentaryEffectPerformed>()
+        .add_event::<CombatLogEvent>()
+        .add_system(perform_effect_system)
+        .add_system(perform_momentary_effect_system.after(perform_effect_system))
+        .add_system(tick_lasting_effects_system.after(perform_effect_system))
+        .add_system(remove_dead_effects_system.after(perform_momentary_effect_system));
+
+    let source = app.world.spawn(Transform::default()).id();
+    let target = app
+        .world
+        .spawn((
+            Health::new(10),
+            PeriodicMomentaryEffects::default(),
+            LastingEffects::default(),
+        ))
+        .id();
+
+    let perform_effects = |app: &mut App, effects: &[Effect]| {
+        app.world.resource_mut::<Events<CombatLogEvent>>().clear();
+        for effect in effects {
+            app.world
+                .resource_mut::<Events<PerformEffect>>()
+                .send(PerformEffect {
+                    effect: *effect,
+                    source,
+                    target,
+                });
+        }
+        app.update();
+
+        let combat_log_events = app.world.resource::<Events<CombatLogEvent>>();
+        let mut combat_log_event_reader = combat_log_events.get_reader();
+        combat_log_event_reader
+            .iter(combat_log_events)
+            .map(|combat_log_event| combat_log_event.kind.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // Aura running out.
+    assert_eq!(
+        perform_effects(&mut app, &[Effect::Lasting(LastingEffect::Silence, 0.0)]),
+        vec![
+            CombatLogEventKind::AuraApplied("Silence"),
+            CombatLogEventKind::AuraExpired("Silence"),
+        ]
+    );
+
+    // Killing blow, clearing the target's auras.
+    assert_eq!(
+        perform_effects(
+            &mut app,
+            &[
+                Effect::Lasting(LastingEffect::Taunt, 60.0),
+                Effect::Momentary(
+                    MomentaryEffect::Damage(10, 10),
+                    MomentaryEffectSchedule::Once
+                ),
+            ]
+        ),
+        vec![
+            CombatLogEventKind::AuraApplied("Taunt"),
+            CombatLogEventKind::Damage(10, false),
+            CombatLogEventKind::Died,
+            CombatLogEventKind::AuraRemoved("Taunt"),
+        ]
+    );
+
+    // Dead targets don't die again.
+    assert_eq!(
+        perform_effects(
+            &mut app,
+            &[Effect::Momentary(
+                MomentaryEffect::Damage(10, 10),
+                MomentaryEffectSchedule::Once
+            )]
+        ),
+        vec![CombatLogEventKind::Damage(10, false)]
+    );
+}
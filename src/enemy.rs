@@ -1,4 +1,51 @@
+use crate::{ai::AiConfig, sprite::Sprite};
 use bevy::prelude::*;
+use serde::Deserialize;
+
+const GOBLIN_STAB_ABILITY_ID: u8 = 4;
 
 #[derive(Component)]
 pub struct Enemy;
+
+/// Component to store which kind of enemy a creature is, as referenced by zone spawns.
+#[derive(Component, Debug, Clone, Copy, Deserialize)]
+pub enum EnemyKind {
+    Goblin,
+}
+
+impl EnemyKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Goblin => "Goblin",
+        }
+    }
+
+    pub fn sprite(&self) -> Sprite {
+        match self {
+            Self::Goblin => Sprite::Goblin,
+        }
+    }
+
+    pub fn health_points(&self) -> u16 {
+        match self {
+            Self::Goblin => 80,
+        }
+    }
+
+    pub fn mana_points(&self) -> u16 {
+        match self {
+            Self::Goblin => 40,
+        }
+    }
+
+    pub fn ai_config(&self) -> AiConfig {
+        match self {
+            Self::Goblin => AiConfig {
+                aggro_range: 160.0,
+                preferred_range: 20.0,
+                retreat_health_fraction: 0.2,
+                ability_ids: vec![GOBLIN_STAB_ABILITY_ID],
+            },
+        }
+    }
+}
@@ -0,0 +1,501 @@
+use crate::spellbook::ActionBar;
+use bevy::{input::InputSystem, prelude::*};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+const INPUT_MAP_PATH: &str = "config/input.ron";
+const GAMEPAD_DEADZONE: f32 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    MoveToCursor,
+    CastSlot1,
+    CastSlot2,
+    CastSlot3,
+    CastSlot4,
+    CastSlot5,
+    CastSlot6,
+    CastSlot7,
+    CastSlot8,
+    CancelCast,
+    SelectTarget,
+    TargetNext,
+    TargetPrevious,
+    ClearTarget,
+    ScrollCombatLogUp,
+    ScrollCombatLogDown,
+    ToggleCombatLogDamageOnly,
+    ToggleCombatLogOwnOnly,
+    Confirm,
+}
+
+impl InputAction {
+    pub const CAST_SLOTS: [Self; ActionBar::SLOT_COUNT] = [
+        Self::CastSlot1,
+        Self::CastSlot2,
+        Self::CastSlot3,
+        Self::CastSlot4,
+        Self::CastSlot5,
+        Self::CastSlot6,
+        Self::CastSlot7,
+        Self::CastSlot8,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Modifier {
+    Shift,
+    Control,
+    Alt,
+    Super,
+}
+
+impl Modifier {
+    fn key_codes(&self) -> [KeyCode; 2] {
+        match self {
+            Self::Shift => [KeyCode::LShift, KeyCode::RShift],
+            Self::Control => [KeyCode::LControl, KeyCode::RControl],
+            Self::Alt => [KeyCode::LAlt, KeyCode::RAlt],
+            Self::Super => [KeyCode::LWin, KeyCode::RWin],
+        }
+    }
+}
+
+/// Input that triggers an action: a key, a key chorded with modifiers, a mouse button or a gamepad button.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Chord(Vec<Modifier>, KeyCode),
+    MouseButton(MouseButton),
+    GamepadButton(GamepadButtonType),
+}
+
+impl InputBinding {
+    fn key_code(&self) -> Option<KeyCode> {
+        match self {
+            Self::Key(key_code) | Self::Chord(_, key_code) => Some(*key_code),
+            Self::MouseButton(_) | Self::GamepadButton(_) => None,
+        }
+    }
+
+    fn modifiers(&self) -> &[Modifier] {
+        match self {
+            Self::Chord(modifiers, _) => modifiers,
+            Self::Key(_) | Self::MouseButton(_) | Self::GamepadButton(_) => &[],
+        }
+    }
+
+    fn is_pressed(&self, raw_input: &RawInput) -> bool {
+        match self {
+            Self::Key(key_code) => raw_input.key_code_input.pressed(*key_code),
+            Self::Chord(modifiers, key_code) => {
+                raw_input.key_code_input.pressed(*key_code)
+                    && modifiers
+                        .iter()
+                        .all(|modifier| raw_input.key_code_input.any_pressed(modifier.key_codes()))
+            }
+            Self::MouseButton(mouse_button) => raw_input.mouse_button_input.pressed(*mouse_button),
+            Self::GamepadButton(button_type) => match raw_input.gamepad {
+                Some(gamepad) => raw_input
+                    .gamepad_button_input
+                    .pressed(GamepadButton::new(gamepad, *button_type)),
+                None => false,
+            },
+        }
+    }
+}
+
+/// Raw input state that bindings are evaluated against.
+struct RawInput<'a> {
+    key_code_input: &'a Input<KeyCode>,
+    mouse_button_input: &'a Input<MouseButton>,
+    gamepad_button_input: &'a Input<GamepadButton>,
+    gamepad: Option<Gamepad>,
+}
+
+/// Event to replace an action's bindings and persist the input map.
+pub struct RebindInputAction {
+    pub action: InputAction,
+    pub bindings: Vec<InputBinding>,
+}
+
+/// Resource to store analog movement from the gamepad's left stick, with the deadzone applied.
+#[derive(Resource, Default)]
+pub struct AnalogMovement {
+    pub direction: Vec2,
+}
+
+/// Resource to map input actions to their bindings, persisted as a user config file.
+#[derive(Resource, Serialize, Deserialize)]
+pub struct InputMap {
+    pub bindings: BTreeMap<InputAction, Vec<InputBinding>>,
+    /// Radial deadzone of the gamepad's left stick, between 0 and 1.
+    #[serde(default = "default_gamepad_deadzone")]
+    pub gamepad_deadzone: f32,
+}
+
+fn default_gamepad_deadzone() -> f32 {
+    GAMEPAD_DEADZONE
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let bindings = [
+            (
+                InputAction::MoveUp,
+                vec![
+                    InputBinding::Key(KeyCode::W),
+                    InputBinding::Key(KeyCode::Up),
+                ],
+            ),
+            (
+                InputAction::MoveDown,
+                vec![
+                    InputBinding::Key(KeyCode::S),
+                    InputBinding::Key(KeyCode::Down),
+                ],
+            ),
+            (
+                InputAction::MoveLeft,
+                vec![
+                    InputBinding::Key(KeyCode::A),
+                    InputBinding::Key(KeyCode::Left),
+                ],
+            ),
+            (
+                InputAction::MoveRight,
+                vec![
+                    InputBinding::Key(KeyCode::D),
+                    InputBinding::Key(KeyCode::Right),
+                ],
+            ),
+            (
+                InputAction::CastSlot1,
+                vec![
+                    InputBinding::Key(KeyCode::Key1),
+                    InputBinding::GamepadButton(GamepadButtonType::South),
+                ],
+            ),
+            (
+                InputAction::CastSlot2,
+                vec![
+                    InputBinding::Key(KeyCode::Key2),
+                    InputBinding::GamepadButton(GamepadButtonType::West),
+                ],
+            ),
+            (
+                InputAction::CastSlot3,
+                vec![
+                    InputBinding::Key(KeyCode::Key3),
+                    InputBinding::GamepadButton(GamepadButtonType::North),
+                ],
+            ),
+            (
+                InputAction::CastSlot4,
+                vec![
+                    InputBinding::Key(KeyCode::Q),
+                    InputBinding::GamepadButton(GamepadButtonType::East),
+                ],
+            ),
+            (
+                InputAction::CastSlot5,
+                vec![
+                    InputBinding::Key(KeyCode::E),
+                    InputBinding::GamepadButton(GamepadButtonType::LeftTrigger2),
+                ],
+            ),
+            (
+                InputAction::CastSlot6,
+                vec![
+                    InputBinding::Key(KeyCode::R),
+                    InputBinding::GamepadButton(GamepadButtonType::RightTrigger2),
+                ],
+            ),
+            (
+                InputAction::CastSlot7,
+                vec![
+                    InputBinding::Key(KeyCode::F),
+                    InputBinding::GamepadButton(GamepadButtonType::LeftThumb),
+                ],
+            ),
+            (
+                InputAction::CastSlot8,
+                vec![
+                    InputBinding::Key(KeyCode::G),
+                    InputBinding::GamepadButton(GamepadButtonType::RightThumb),
+                ],
+            ),
+            (
+                InputAction::CancelCast,
+                vec![
+                    InputBinding::Key(KeyCode::Escape),
+                    InputBinding::GamepadButton(GamepadButtonType::Select),
+                ],
+            ),
+            (
+                InputAction::MoveToCursor,
+                vec![InputBinding::MouseButton(MouseButton::Right)],
+            ),
+            (
+                InputAction::SelectTarget,
+                vec![InputBinding::MouseButton(MouseButton::Left)],
+            ),
+            (
+                InputAction::TargetNext,
+                vec![
+                    InputBinding::Key(KeyCode::Tab),
+                    InputBinding::GamepadButton(GamepadButtonType::RightTrigger),
+                ],
+            ),
+            (
+                InputAction::TargetPrevious,
+                vec![
+                    InputBinding::Chord(vec![Modifier::Shift], KeyCode::Tab),
+                    InputBinding::GamepadButton(GamepadButtonType::LeftTrigger),
+                ],
+            ),
+            (
+                InputAction::ClearTarget,
+                vec![InputBinding::Key(KeyCode::Escape)],
+            ),
+            (
+                InputAction::ScrollCombatLogUp,
+                vec![InputBinding::Key(KeyCode::PageUp)],
+            ),
+            (
+                InputAction::ScrollCombatLogDown,
+                vec![InputBinding::Key(KeyCode::PageDown)],
+            ),
+            (
+                InputAction::ToggleCombatLogDamageOnly,
+                vec![InputBinding::Key(KeyCode::F1)],
+            ),
+            (
+                InputAction::ToggleCombatLogOwnOnly,
+                vec![InputBinding::Key(KeyCode::F2)],
+            ),
+            (
+                InputAction::Confirm,
+                vec![
+                    InputBinding::Key(KeyCode::Return),
+                    InputBinding::Key(KeyCode::NumpadEnter),
+                    InputBinding::GamepadButton(GamepadButtonType::Start),
+                ],
+            ),
+        ];
+
+        Self {
+            bindings: bindings.into_iter().collect(),
+            gamepad_deadzone: GAMEPAD_DEADZONE,
+        }
+    }
+}
+
+impl InputMap {
+    fn load(path: &Path) -> Option<Self> {
+        let string = fs::read_to_string(path).ok()?;
+
+        match ron::from_str::<Self>(&string) {
+            Ok(mut result) => {
+                // Keep default bindings for actions added since the file was saved.
+                for (action, bindings) in Self::default().bindings {
+                    result.bindings.entry(action).or_insert(bindings);
+                }
+
+                Some(result)
+            }
+            Err(error) => {
+                warn!("Invalid input map at {path:?}, using defaults: {error}");
+
+                None
+            }
+        }
+    }
+
+    fn save(&self, path: &Path) {
+        let string = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(result) => result,
+            Err(error) => {
+                warn!("Couldn't serialize input map: {error}");
+
+                return;
+            }
+        };
+
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        if let Err(error) = fs::write(path, string) {
+            warn!("Couldn't save input map at {path:?}: {error}");
+        }
+    }
+
+    /// Returns whether a binding is pressed. A binding is suppressed while a more specific chord of the same key is
+    /// pressed, so that Shift+Tab doesn't also trigger Tab.
+    fn is_binding_pressed(&self, binding: &InputBinding, raw_input: &RawInput) -> bool {
+        binding.is_pressed(raw_input)
+            && !self.bindings.values().flatten().any(|other_binding| {
+                other_binding.key_code().is_some()
+                    && other_binding.key_code() == binding.key_code()
+                    && other_binding.modifiers().len() > binding.modifiers().len()
+                    && other_binding.is_pressed(raw_input)
+            })
+    }
+
+    /// Applies the radial deadzone to a stick position, rescaling the remaining range so magnitude starts from zero.
+    fn apply_gamepad_deadzone(&self, position: Vec2) -> Vec2 {
+        let length = position.length();
+        if length <= self.gamepad_deadzone {
+            return Vec2::ZERO;
+        }
+
+        let scaled_length =
+            ((length - self.gamepad_deadzone) / (1.0 - self.gamepad_deadzone)).min(1.0);
+
+        position / length * scaled_length
+    }
+}
+
+pub struct InputMapPlugin;
+
+impl Plugin for InputMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RebindInputAction>()
+            .init_resource::<InputMap>()
+            .init_resource::<Input<InputAction>>()
+            .init_resource::<AnalogMovement>()
+            .add_startup_system(load_system)
+            .add_system_to_stage(CoreStage::PreUpdate, update_system.after(InputSystem))
+            .add_system(rebind_system);
+    }
+}
+
+fn load_system(mut input_map: ResMut<InputMap>) {
+    let path = Path::new(INPUT_MAP_PATH);
+    if path.exists() {
+        if let Some(loaded_input_map) = InputMap::load(path) {
+            *input_map = loaded_input_map;
+        }
+    } else {
+        input_map.save(path);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_system(
+    input_map: Res<InputMap>,
+    key_code_input: Res<Input<KeyCode>>,
+    mouse_button_input: Res<Input<MouseButton>>,
+    gamepad_button_input: Res<Input<GamepadButton>>,
+    gamepad_axis: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    mut action_input: ResMut<Input<InputAction>>,
+    mut analog_movement: ResMut<AnalogMovement>,
+) {
+    let gamepad = gamepads.iter().next();
+    let raw_input = RawInput {
+        key_code_input: &key_code_input,
+        mouse_button_input: &mouse_button_input,
+        gamepad_button_input: &gamepad_button_input,
+        gamepad,
+    };
+
+    action_input.clear();
+
+    for (action, bindings) in input_map.bindings.iter() {
+        let is_pressed = bindings
+            .iter()
+            .any(|binding| input_map.is_binding_pressed(binding, &raw_input));
+
+        if is_pressed {
+            action_input.press(*action);
+        } else {
+            action_input.release(*action);
+        }
+    }
+
+    analog_movement.direction = match gamepad {
+        Some(gamepad) => {
+            let axis_value = |axis_type| {
+                gamepad_axis
+                    .get(GamepadAxis::new(gamepad, axis_type))
+                    .unwrap_or(0.0)
+            };
+            let position = Vec2::new(
+                axis_value(GamepadAxisType::LeftStickX),
+                axis_value(GamepadAxisType::LeftStickY),
+            );
+
+            input_map.apply_gamepad_deadzone(position)
+        }
+        None => Vec2::ZERO,
+    };
+}
+
+fn rebind_system(
+    mut rebind_input_action_event_reader: EventReader<RebindInputAction>,
+    mut input_map: ResMut<InputMap>,
+) {
+    let mut is_changed = false;
+    for rebind_input_action in rebind_input_action_event_reader.iter() {
+        input_map.bindings.insert(
+            rebind_input_action.action,
+            rebind_input_action.bindings.clone(),
+        );
+        is_changed = true;
+    }
+
+    if is_changed {
+        input_map.save(Path::new(INPUT_MAP_PATH));
+    }
+}
+
+#[test]
+fn test_input_map_chord() {
+    let mut input_map = InputMap::default();
+    input_map.bindings.insert(
+        InputAction::CastSlot1,
+        vec![InputBinding::Key(KeyCode::Key1)],
+    );
+    input_map.bindings.insert(
+        InputAction::CastSlot2,
+        vec![InputBinding::Chord(vec![Modifier::Shift], KeyCode::Key1)],
+    );
+
+    let mut key_code_input = Input::<KeyCode>::default();
+    let mouse_button_input = Input::<MouseButton>::default();
+    let gamepad_button_input = Input::<GamepadButton>::default();
+
+    key_code_input.press(KeyCode::Key1);
+    let raw_input = RawInput {
+        key_code_input: &key_code_input,
+        mouse_button_input: &mouse_button_input,
+        gamepad_button_input: &gamepad_button_input,
+        gamepad: None,
+    };
+    assert!(
+        input_map.is_binding_pressed(&input_map.bindings[&InputAction::CastSlot1][0], &raw_input)
+    );
+    assert!(
+        !input_map.is_binding_pressed(&input_map.bindings[&InputAction::CastSlot2][0], &raw_input)
+    );
+
+    key_code_input.press(KeyCode::RShift);
+    let raw_input = RawInput {
+        key_code_input: &key_code_input,
+        mouse_button_input: &mouse_button_input,
+        gamepad_button_input: &gamepad_button_input,
+        gamepad: None,
+    };
+    assert!(
+        !input_map.is_binding_pressed(&input_map.bindings[&InputAction::CastSlot1][0], &raw_input)
+    );
+    assert!(
+        input_map.is_binding_pressed(&input_map.bindings[&InputAction::CastSlot2][0], &raw_input)
+    );
+}
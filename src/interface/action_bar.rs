@@ -0,0 +1,253 @@
+use super::{
+    hud::{Hud, HudAlignment, HudAnchor},
+    TRANSLATION_Z,
+};
+use crate::{
+    ability::{verify_target_position, AbilityCooldowns, AbilityGlobalCooldown},
+    ability_registry::AbilityRegistry,
+    level::Obstacle,
+    mana::Mana,
+    player::Player,
+    spellbook::ActionBar,
+    target::Target,
+    AppState,
+};
+use bevy::{prelude::*, sprite::Anchor};
+
+const SLOT_SIZE: f32 = 24.0;
+const SLOT_SPACING: f32 = 4.0;
+const MARGIN: f32 = 8.0;
+
+const FONT_PATH: &str = "fonts/04b03.ttf";
+const FONT_SIZE: f32 = 12.0;
+
+const COLOR: Color = Color::rgba(0.3, 0.3, 0.3, 0.8);
+const UNUSABLE_COLOR: Color = Color::rgba(0.1, 0.1, 0.1, 0.8);
+const OUT_OF_RANGE_COLOR: Color = Color::rgba(0.6, 0.1, 0.1, 0.8);
+const SWEEP_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
+const TEXT_COLOR: Color = Color::WHITE;
+const UNUSABLE_TEXT_COLOR: Color = Color::GRAY;
+
+/// Component for an action bar slot of the entity.
+#[derive(Component)]
+struct ActionBarSlot {
+    entity: Entity,
+    slot: usize,
+}
+
+/// Component for the overlay covering a slot for the remaining fraction of its cooldown.
+#[derive(Component)]
+struct ActionBarSweep;
+
+pub struct ActionBarPlugin;
+
+impl Plugin for ActionBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(AppState::Game)
+                .with_system(spawn_system)
+                .with_system(update_system),
+        )
+        .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+fn spawn_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    query: Query<Entity, Added<Player>>,
+    hud_query: Query<Entity, With<Hud>>,
+) {
+    let entity = match query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    let text_style = TextStyle {
+        font: asset_server.load(FONT_PATH),
+        font_size: FONT_SIZE,
+        color: TEXT_COLOR,
+    };
+
+    let width = ActionBar::SLOT_COUNT as f32 * (SLOT_SIZE + SLOT_SPACING) - SLOT_SPACING;
+    let slot_entities = (0..ActionBar::SLOT_COUNT)
+        .map(|slot| {
+            commands
+                .spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(Vec2::splat(SLOT_SIZE)),
+                            color: COLOR,
+                            ..default()
+                        },
+                        transform: Transform::from_translation(Vec3::new(0.0, 0.0, TRANSLATION_Z)),
+                        ..default()
+                    },
+                    HudAnchor::new(
+                        HudAlignment::Bottom,
+                        Vec2::new(
+                            -width / 2.0
+                                + SLOT_SIZE / 2.0
+                                + slot as f32 * (SLOT_SIZE + SLOT_SPACING),
+                            SLOT_SIZE / 2.0 + MARGIN,
+                        ),
+                    ),
+                    ActionBarSlot { entity, slot },
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::new(SLOT_SIZE, 0.0)),
+                                color: SWEEP_COLOR,
+                                anchor: Anchor::BottomCenter,
+                                ..default()
+                            },
+                            transform: Transform::from_translation(Vec3::new(
+                                0.0,
+                                -SLOT_SIZE / 2.0,
+                                1.0,
+                            )),
+                            ..default()
+                        },
+                        ActionBarSweep,
+                    ));
+
+                    parent.spawn(Text2dBundle {
+                        text: Text::from_section("", text_style.clone())
+                            .with_alignment(TextAlignment::CENTER),
+                        transform: Transform::from_translation(Vec3::new(0.0, 0.0, 2.0)),
+                        ..default()
+                    });
+                })
+                .id()
+        })
+        .collect::<Vec<_>>();
+
+    commands
+        .entity(hud_query.single())
+        .push_children(&slot_entities);
+}
+
+#[allow(clippy::type_complexity)]
+fn update_system(
+    ability_registry: Res<AbilityRegistry>,
+    mut query: Query<
+        (&Children, &mut Sprite, &mut Visibility, &ActionBarSlot),
+        Without<ActionBarSweep>,
+    >,
+    mut child_sweep_query: Query<&mut Sprite, With<ActionBarSweep>>,
+    mut child_text_query: Query<&mut Text>,
+    player_query: Query<(
+        &Transform,
+        &ActionBar,
+        &Mana,
+        &AbilityCooldowns,
+        &Target,
+        Option<&AbilityGlobalCooldown>,
+    )>,
+    target_query: Query<&Transform>,
+    obstacle_query: Query<&Transform, With<Obstacle>>,
+) {
+    for (children, mut sprite, mut visibility, action_bar_slot) in query.iter_mut() {
+        let (transform, action_bar, mana, ability_cooldowns, target, ability_global_cooldown) =
+            match player_query.get(action_bar_slot.entity) {
+                Ok(result) => result,
+                Err(_) => continue,
+            };
+
+        let ability = match action_bar
+            .ability_id(action_bar_slot.slot)
+            .and_then(|ability_id| ability_registry.get(ability_id))
+        {
+            Some(result) => result,
+            None => {
+                visibility.is_visible = false;
+
+                continue;
+            }
+        };
+
+        visibility.is_visible = true;
+
+        let ability_cooldown_timer = ability_cooldowns.duration_timer(ability.id);
+        let cooldown_fraction = ability_cooldown_timer
+            .into_iter()
+            .chain(
+                ability_global_cooldown
+                    .map(|ability_global_cooldown| ability_global_cooldown.duration_timer()),
+            )
+            .map(|duration_timer| duration_timer.percent_left())
+            .fold(0.0, f32::max);
+
+        // Abilities on the entity itself are always in range.
+        let is_out_of_range = ability.requires_target()
+            && match target.entity {
+                Some(target_entity) if target_entity != action_bar_slot.entity => {
+                    match target_query.get(target_entity) {
+                        Ok(target_transform) => verify_target_position(
+                            transform.translation.truncate(),
+                            target_transform.translation.truncate(),
+                            ability.range,
+                            &obstacle_query,
+                        )
+                        .is_err(),
+                        Err(_) => false,
+                    }
+                }
+                _ => false,
+            };
+        let is_usable = ability.mana_points <= mana.points;
+
+        sprite.color = if !is_usable {
+            UNUSABLE_COLOR
+        } else if is_out_of_range {
+            OUT_OF_RANGE_COLOR
+        } else {
+            COLOR
+        };
+
+        for &child in children.iter() {
+            if let Ok(mut sweep_sprite) = child_sweep_query.get_mut(child) {
+                sweep_sprite.custom_size = Some(Vec2::new(
+                    SLOT_SIZE,
+                    (SLOT_SIZE * cooldown_fraction).round(),
+                ));
+            }
+
+            if let Ok(mut text) = child_text_query.get_mut(child) {
+                // Show the seconds left of the ability's own cooldown, otherwise its initials.
+                text.sections[0].value = match ability_cooldown_timer {
+                    Some(duration_timer) => {
+                        format!("{}", duration_timer.remaining_secs().ceil())
+                    }
+                    None => initials(&ability.name),
+                };
+                text.sections[0].style.color = if is_usable {
+                    TEXT_COLOR
+                } else {
+                    UNUSABLE_TEXT_COLOR
+                };
+            }
+        }
+    }
+}
+
+/// Returns the first letter of each word, or the first two letters of a single word.
+fn initials(name: &str) -> String {
+    let words = name.split_whitespace().collect::<Vec<_>>();
+    if words.len() == 1 {
+        return words[0].chars().take(2).collect();
+    }
+
+    words
+        .iter()
+        .filter_map(|word| word.chars().next())
+        .collect()
+}
+
+fn despawn_system(mut commands: Commands, query: Query<Entity, With<ActionBarSlot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,370 @@
+use super::{
+    bar::{ENEMY_LABEL_OFFSET, NAMEPLATE_FONT_SIZE, PLAYER_HEIGHT, PLAYER_MARGIN},
+    hud::{Hud, HudAlignment, HudAnchor},
+    TRANSLATION_Z,
+};
+use crate::{
+    cursor::WorldCursor,
+    effect::{LastingEffect, LastingEffects, MomentaryEffect, PeriodicMomentaryEffects},
+    enemy::Enemy,
+    player::Player,
+    AppState,
+};
+use bevy::prelude::*;
+
+const ICON_SIZE: f32 = 12.0;
+const BORDER_WIDTH: f32 = 1.0;
+const ICON_SPACING: f32 = 2.0;
+/// Below the player's mana bar, spaced as the bars are.
+const PLAYER_OFFSET: Vec2 = Vec2::new(
+    PLAYER_MARGIN + ICON_SIZE / 2.0 + BORDER_WIDTH,
+    -(PLAYER_HEIGHT + PLAYER_MARGIN) * 2.0 - ICON_SIZE / 2.0 - BORDER_WIDTH,
+);
+/// Above enemies' nameplate.
+const ENEMY_OFFSET_Y: f32 =
+    ENEMY_LABEL_OFFSET + NAMEPLATE_FONT_SIZE / 2.0 + ICON_SPACING + ICON_SIZE / 2.0 + BORDER_WIDTH;
+const TOOLTIP_OFFSET_Y: f32 = 10.0;
+const TOOLTIP_TRANSLATION_Z: f32 = 60.0;
+
+const FONT_PATH: &str = "fonts/04b03.ttf";
+const FONT_SIZE: f32 = 8.0;
+const TOOLTIP_FONT_SIZE: f32 = 12.0;
+
+const SILENCE_COLOR: Color = Color::rgb(0.6, 0.3, 0.9);
+const TAUNT_COLOR: Color = Color::rgb(1.0, 0.6, 0.1);
+const DAMAGE_COLOR: Color = Color::rgb(231.0 / 255.0, 39.0 / 255.0, 37.0 / 255.0);
+const HEAL_COLOR: Color = Color::rgb(0.0, 231.0 / 255.0, 0.0);
+const OWN_BORDER_COLOR: Color = Color::rgb(1.0, 0.85, 0.2);
+const OTHER_BORDER_COLOR: Color = Color::rgb(0.4, 0.4, 0.4);
+
+#[derive(Clone, Copy, PartialEq)]
+enum AuraKind {
+    Silence,
+    Taunt,
+    Damage,
+    Heal,
+}
+
+impl AuraKind {
+    fn color(&self) -> Color {
+        match self {
+            Self::Silence => SILENCE_COLOR,
+            Self::Taunt => TAUNT_COLOR,
+            Self::Damage => DAMAGE_COLOR,
+            Self::Heal => HEAL_COLOR,
+        }
+    }
+}
+
+/// Lasting effect or periodic momentary effect instance on a creature.
+struct Aura {
+    kind: AuraKind,
+    source: Entity,
+    remaining_secs: f32,
+    description: String,
+}
+
+/// Returns the creature's auras, lasting effects first.
+fn auras(
+    lasting_effects: &LastingEffects,
+    periodic_momentary_effects: &PeriodicMomentaryEffects,
+) -> Vec<Aura> {
+    let lasting_auras = lasting_effects.instances.iter().map(|instance| {
+        let (kind, description) = match instance.effect {
+            LastingEffect::Silence => (AuraKind::Silence, "Silenced: can't use abilities."),
+            LastingEffect::Taunt => (AuraKind::Taunt, "Taunted: forced to attack the source."),
+        };
+
+        Aura {
+            kind,
+            source: instance.source,
+            remaining_secs: instance.duration_timer.remaining_secs(),
+            description: description.to_string(),
+        }
+    });
+
+    let periodic_auras = periodic_momentary_effects.instances.iter().map(|instance| {
+        let interval = instance.interval_timer.duration().as_secs_f32();
+        let (kind, description) = match instance.effect {
+            MomentaryEffect::Damage(min_points, max_points) => (
+                AuraKind::Damage,
+                format!("Takes {min_points}-{max_points} damage every {interval}s."),
+            ),
+            MomentaryEffect::Heal(min_points, max_points) => (
+                AuraKind::Heal,
+                format!("Heals {min_points}-{max_points} every {interval}s."),
+            ),
+        };
+
+        Aura {
+            kind,
+            source: instance.source,
+            remaining_secs: instance.duration_timer.remaining_secs(),
+            description,
+        }
+    });
+
+    lasting_auras.chain(periodic_auras).collect()
+}
+
+/// Component for a row of aura icons of the entity. Icons are spawned again once the kinds or sources of auras change.
+#[derive(Component)]
+struct AuraRow {
+    entity: Entity,
+    is_centered: bool,
+    kinds: Vec<(AuraKind, bool)>,
+}
+
+impl AuraRow {
+    fn new(entity: Entity, is_centered: bool) -> Self {
+        Self {
+            entity,
+            is_centered,
+            kinds: Vec::new(),
+        }
+    }
+}
+
+/// Component for the icon of the entity's aura at the index.
+#[derive(Component)]
+struct AuraIcon {
+    entity: Entity,
+    index: usize,
+}
+
+#[derive(Component)]
+struct AuraTooltip;
+
+pub struct AuraPlugin;
+
+impl Plugin for AuraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn_tooltip_system))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(spawn_player_system)
+                    .with_system(spawn_enemy_system)
+                    .with_system(update_row_system)
+                    .with_system(update_duration_system)
+                    .with_system(update_tooltip_system),
+            )
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+fn spawn_tooltip_system(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = TextStyle {
+        font: asset_server.load(FONT_PATH),
+        font_size: TOOLTIP_FONT_SIZE,
+        color: Color::WHITE,
+    };
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section("", text_style).with_alignment(TextAlignment::BOTTOM_CENTER),
+            visibility: Visibility { is_visible: false },
+            ..default()
+        },
+        AuraTooltip,
+    ));
+}
+
+fn spawn_player_system(
+    mut commands: Commands,
+    query: Query<Entity, Added<Player>>,
+    hud_query: Query<Entity, With<Hud>>,
+) {
+    let entity = match query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    let row_entity = commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(0.0, 0.0, TRANSLATION_Z)),
+            HudAnchor::new(HudAlignment::TopLeft, PLAYER_OFFSET),
+            AuraRow::new(entity, false),
+        ))
+        .id();
+
+    commands.entity(hud_query.single()).add_child(row_entity);
+}
+
+fn spawn_enemy_system(mut commands: Commands, query: Query<Entity, Added<Enemy>>) {
+    for entity in query.iter() {
+        let row_entity = commands
+            .spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    0.0,
+                    ENEMY_OFFSET_Y,
+                    TRANSLATION_Z,
+                )),
+                AuraRow::new(entity, true),
+            ))
+            .id();
+
+        commands.entity(entity).add_child(row_entity);
+    }
+}
+
+fn update_row_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(Entity, &mut AuraRow)>,
+    effects_query: Query<(&LastingEffects, &PeriodicMomentaryEffects)>,
+    player_query: Query<Entity, With<Player>>,
+) {
+    let player_entity = player_query.get_single().ok();
+
+    for (row_entity, mut aura_row) in query.iter_mut() {
+        let (lasting_effects, periodic_momentary_effects) = match effects_query.get(aura_row.entity)
+        {
+            Ok(result) => result,
+            Err(_) => continue,
+        };
+
+        let auras = auras(lasting_effects, periodic_momentary_effects);
+        let kinds = auras
+            .iter()
+            .map(|aura| (aura.kind, Some(aura.source) == player_entity))
+            .collect::<Vec<_>>();
+        if kinds == aura_row.kinds {
+            continue;
+        }
+
+        let step = ICON_SIZE + BORDER_WIDTH * 2.0 + ICON_SPACING;
+        let start_x = if aura_row.is_centered {
+            -(kinds.len().saturating_sub(1) as f32) * step / 2.0
+        } else {
+            0.0
+        };
+
+        commands.entity(row_entity).despawn_descendants();
+        for (index, (kind, is_own)) in kinds.iter().enumerate() {
+            let text_style = TextStyle {
+                font: asset_server.load(FONT_PATH),
+                font_size: FONT_SIZE,
+                color: Color::WHITE,
+            };
+
+            let icon_entity = commands
+                .spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(Vec2::splat(ICON_SIZE + BORDER_WIDTH * 2.0)),
+                            color: if *is_own {
+                                OWN_BORDER_COLOR
+                            } else {
+                                OTHER_BORDER_COLOR
+                            },
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(start_x + index as f32 * step, 0.0, 0.0),
+                        ..default()
+                    },
+                    AuraIcon {
+                        entity: aura_row.entity,
+                        index,
+                    },
+                ))
+                .with_children(|parent| {
+                    parent.spawn(SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(Vec2::splat(ICON_SIZE)),
+                            color: kind.color(),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                        ..default()
+                    });
+
+                    parent.spawn(Text2dBundle {
+                        text: Text::from_section("", text_style)
+                            .with_alignment(TextAlignment::CENTER),
+                        transform: Transform::from_xyz(0.0, 0.0, 2.0),
+                        ..default()
+                    });
+                })
+                .id();
+
+            commands.entity(row_entity).add_child(icon_entity);
+        }
+
+        aura_row.kinds = kinds;
+    }
+}
+
+fn update_duration_system(
+    query: Query<(&Children, &AuraIcon)>,
+    mut child_text_query: Query<&mut Text>,
+    effects_query: Query<(&LastingEffects, &PeriodicMomentaryEffects)>,
+) {
+    for (children, aura_icon) in query.iter() {
+        let (lasting_effects, periodic_momentary_effects) =
+            match effects_query.get(aura_icon.entity) {
+                Ok(result) => result,
+                Err(_) => continue,
+            };
+
+        let aura = match auras(lasting_effects, periodic_momentary_effects)
+            .into_iter()
+            .nth(aura_icon.index)
+        {
+            Some(result) => result,
+            None => continue,
+        };
+
+        for &child in children.iter() {
+            if let Ok(mut text) = child_text_query.get_mut(child) {
+                text.sections[0].value = format!("{}", aura.remaining_secs.ceil());
+            }
+        }
+    }
+}
+
+fn update_tooltip_system(
+    world_cursor: Res<WorldCursor>,
+    mut query: Query<(&mut Transform, &mut Visibility, &mut Text), With<AuraTooltip>>,
+    icon_query: Query<(&GlobalTransform, &AuraIcon)>,
+    effects_query: Query<(&LastingEffects, &PeriodicMomentaryEffects)>,
+) {
+    let (mut transform, mut visibility, mut text) = match query.get_single_mut() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    let hovered_aura = world_cursor.position.and_then(|cursor_position| {
+        let (_, aura_icon) = icon_query.iter().find(|(global_transform, _)| {
+            let offset = (cursor_position - global_transform.translation().truncate()).abs();
+
+            offset.max_element() <= ICON_SIZE / 2.0 + BORDER_WIDTH
+        })?;
+        let (lasting_effects, periodic_momentary_effects) =
+            effects_query.get(aura_icon.entity).ok()?;
+
+        auras(lasting_effects, periodic_momentary_effects)
+            .into_iter()
+            .nth(aura_icon.index)
+            .map(|aura| (cursor_position, aura))
+    });
+
+    match hovered_aura {
+        Some((cursor_position, aura)) => {
+            transform.translation =
+                (cursor_position + Vec2::new(0.0, TOOLTIP_OFFSET_Y)).extend(TOOLTIP_TRANSLATION_Z);
+            text.sections[0].value = aura.description;
+            visibility.is_visible = true;
+        }
+        None => visibility.is_visible = false,
+    }
+}
+
+fn despawn_system(
+    mut commands: Commands,
+    row_query: Query<Entity, With<AuraRow>>,
+    tooltip_query: Query<Entity, With<AuraTooltip>>,
+) {
+    for entity in row_query.iter().chain(tooltip_query.iter()) {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,23 +1,33 @@
-use super::{HEIGHT, TRANSLATION_Z, WIDTH};
+use super::{
+    hud::{Hud, HudAlignment, HudAnchor},
+    TRANSLATION_Z,
+};
 use crate::{
     ability::CastAbility, enemy::Enemy, health::Health, mana::Mana, player::Player, AppState,
 };
 use bevy::{ecs::component::Component, prelude::*};
 
-const PLAYER_WIDTH: f32 = 104.0;
+pub(super) const PLAYER_WIDTH: f32 = 104.0;
 const PLAYER_CAST_WIDTH: f32 = 144.0;
-const PLAYER_HEIGHT: f32 = 16.0;
-const ENEMY_HEIGHT: f32 = 4.0;
-const PLAYER_MARGIN: f32 = 8.0;
-const ENEMY_MARGIN: f32 = 1.0;
+pub(super) const PLAYER_HEIGHT: f32 = 16.0;
+pub(super) const ENEMY_HEIGHT: f32 = 4.0;
+pub(super) const PLAYER_MARGIN: f32 = 8.0;
+pub(super) const PLAYER_CAST_OFFSET: f32 = 75.0;
+pub(super) const ENEMY_MARGIN: f32 = 1.0;
+pub(super) const NAMEPLATE_FONT_SIZE: f32 = 8.0;
+/// From an enemy's center to its health and cast bars' centers.
+const ENEMY_BAR_OFFSET: f32 = (crate::Sprite::SIZE + ENEMY_HEIGHT) / 2.0 + ENEMY_MARGIN;
+/// From an enemy's center to its name's center.
+pub(super) const ENEMY_LABEL_OFFSET: f32 =
+    ENEMY_BAR_OFFSET + (ENEMY_HEIGHT + NAMEPLATE_FONT_SIZE) / 2.0 + ENEMY_MARGIN;
 const TEXT_VERTICAL_OFFSET: f32 = -0.5;
 
-const FONT_PATH: &str = "fonts/04b03.ttf";
-const FONT_SIZE: f32 = 12.0;
+pub(super) const FONT_PATH: &str = "fonts/04b03.ttf";
+pub(super) const FONT_SIZE: f32 = 12.0;
 
-const HEALTH_COLOR: Color = Color::rgb(231.0 / 255.0, 39.0 / 255.0, 37.0 / 255.0);
-const MANA_COLOR: Color = Color::rgb(43.0 / 255.0, 102.0 / 255.0, 201.0 / 255.0);
-const CAST_COLOR: Color = Color::rgb(1.0, 240.0 / 255.0, 0.0);
+pub(super) const HEALTH_COLOR: Color = Color::rgb(231.0 / 255.0, 39.0 / 255.0, 37.0 / 255.0);
+pub(super) const MANA_COLOR: Color = Color::rgb(43.0 / 255.0, 102.0 / 255.0, 201.0 / 255.0);
+pub(super) const CAST_COLOR: Color = Color::rgb(1.0, 240.0 / 255.0, 0.0);
 const BACKGROUND_COLOR_ALPHA: f32 = 0.25;
 
 trait Progressive {
@@ -51,7 +61,7 @@ impl Progressive for CastAbility {
     }
 
     fn get_progress_description(&self) -> String {
-        self.ability.name.to_string()
+        self.ability.name.clone()
     }
 }
 
@@ -85,19 +95,23 @@ impl Bar for CastBar {
     }
 }
 
+/// Component for text naming what bars belong to.
+#[derive(Component)]
+struct Label;
+
 #[derive(Component)]
-struct HealthBar {
-    entity: Entity,
+pub(super) struct HealthBar {
+    pub(super) entity: Entity,
 }
 
 #[derive(Component)]
-struct ManaBar {
-    entity: Entity,
+pub(super) struct ManaBar {
+    pub(super) entity: Entity,
 }
 
 #[derive(Component)]
-struct CastBar {
-    entity: Entity,
+pub(super) struct CastBar {
+    pub(super) entity: Entity,
 }
 
 pub struct BarPlugin;
@@ -124,6 +138,7 @@ fn spawn_player_system(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     query: Query<Entity, Added<Player>>,
+    hud_query: Query<Entity, With<Hud>>,
 ) {
     let entity = match query.get_single() {
         Ok(result) => result,
@@ -132,13 +147,9 @@ fn spawn_player_system(
 
     let font = asset_server.load(FONT_PATH);
 
-    spawn(
+    let health_entity = spawn(
         HEALTH_COLOR,
-        Vec3::new(
-            WIDTH * -0.5 + PLAYER_WIDTH * 0.5 + PLAYER_MARGIN,
-            HEIGHT * 0.5 - PLAYER_HEIGHT * 0.5 - PLAYER_MARGIN,
-            TRANSLATION_Z,
-        ),
+        Vec3::new(0.0, 0.0, TRANSLATION_Z),
         Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT),
         true,
         HealthBar { entity },
@@ -146,13 +157,9 @@ fn spawn_player_system(
         Some(font.clone()),
     );
 
-    spawn(
+    let mana_entity = spawn(
         MANA_COLOR,
-        Vec3::new(
-            WIDTH * -0.5 + PLAYER_WIDTH * 0.5 + PLAYER_MARGIN,
-            HEIGHT * 0.5 - PLAYER_HEIGHT * 1.5 - PLAYER_MARGIN * 1.5,
-            TRANSLATION_Z,
-        ),
+        Vec3::new(0.0, 0.0, TRANSLATION_Z),
         Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT),
         true,
         ManaBar { entity },
@@ -160,34 +167,80 @@ fn spawn_player_system(
         Some(font.clone()),
     );
 
-    spawn(
+    let cast_entity = spawn(
         CAST_COLOR,
-        Vec3::new(0.0, HEIGHT / -4.0, TRANSLATION_Z),
+        Vec3::new(0.0, 0.0, TRANSLATION_Z),
         Vec2::new(PLAYER_CAST_WIDTH, PLAYER_HEIGHT),
         false,
         CastBar { entity },
         &mut commands,
         Some(font),
     );
+
+    commands.entity(health_entity).insert(HudAnchor::new(
+        HudAlignment::TopLeft,
+        Vec2::new(
+            PLAYER_WIDTH * 0.5 + PLAYER_MARGIN,
+            -PLAYER_HEIGHT * 0.5 - PLAYER_MARGIN,
+        ),
+    ));
+    commands.entity(mana_entity).insert(HudAnchor::new(
+        HudAlignment::TopLeft,
+        Vec2::new(
+            PLAYER_WIDTH * 0.5 + PLAYER_MARGIN,
+            -PLAYER_HEIGHT * 1.5 - PLAYER_MARGIN * 1.5,
+        ),
+    ));
+    commands.entity(cast_entity).insert(HudAnchor::new(
+        HudAlignment::Bottom,
+        Vec2::new(0.0, PLAYER_CAST_OFFSET),
+    ));
+
+    commands
+        .entity(hud_query.single())
+        .push_children(&[health_entity, mana_entity, cast_entity]);
 }
 
-fn spawn_enemy_system(mut commands: Commands, query: Query<Entity, Added<Enemy>>) {
-    for entity in query.iter() {
+/// Spawns a nameplate for each enemy, with its name and health above it and its cast below it.
+fn spawn_enemy_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    query: Query<(Entity, &Name), Added<Enemy>>,
+) {
+    for (entity, name) in query.iter() {
+        let size = Vec2::new(crate::Sprite::SIZE, ENEMY_HEIGHT);
+
+        let name_entity = spawn_label(
+            name.to_string(),
+            asset_server.load(FONT_PATH),
+            NAMEPLATE_FONT_SIZE,
+            Vec3::new(0.0, ENEMY_LABEL_OFFSET, TRANSLATION_Z),
+            &mut commands,
+        );
+
         let health_entity = spawn(
             HEALTH_COLOR,
-            Vec3::new(
-                0.0,
-                (crate::Sprite::SIZE + ENEMY_HEIGHT) / 2.0 + ENEMY_MARGIN,
-                TRANSLATION_Z,
-            ),
-            Vec2::new(crate::Sprite::SIZE, ENEMY_HEIGHT),
+            Vec3::new(0.0, ENEMY_BAR_OFFSET, TRANSLATION_Z),
+            size,
             true,
             HealthBar { entity },
             &mut commands,
             None,
         );
 
-        commands.entity(entity).add_child(health_entity);
+        let cast_entity = spawn(
+            CAST_COLOR,
+            Vec3::new(0.0, -ENEMY_BAR_OFFSET, TRANSLATION_Z),
+            size,
+            false,
+            CastBar { entity },
+            &mut commands,
+            None,
+        );
+
+        commands
+            .entity(entity)
+            .push_children(&[name_entity, health_entity, cast_entity]);
     }
 }
 
@@ -263,17 +316,19 @@ fn despawn_system(
     health_query: Query<Entity, With<HealthBar>>,
     mana_query: Query<Entity, With<ManaBar>>,
     cast_query: Query<Entity, With<CastBar>>,
+    label_query: Query<Entity, With<Label>>,
 ) {
     for entity in health_query
         .iter()
         .chain(mana_query.iter())
         .chain(cast_query.iter())
+        .chain(label_query.iter())
     {
         commands.entity(entity).despawn_recursive();
     }
 }
 
-fn spawn<T: Component>(
+pub(super) fn spawn<T: Component>(
     color: Color,
     translation: Vec3,
     size: Vec2,
@@ -329,3 +384,28 @@ fn spawn<T: Component>(
         })
         .id()
 }
+
+pub(super) fn spawn_label(
+    value: String,
+    font_handle: Handle<Font>,
+    font_size: f32,
+    translation: Vec3,
+    commands: &mut Commands,
+) -> Entity {
+    let text_style = TextStyle {
+        font: font_handle,
+        font_size,
+        color: Color::WHITE,
+    };
+
+    commands
+        .spawn((
+            Text2dBundle {
+                text: Text::from_section(value, text_style).with_alignment(TextAlignment::CENTER),
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+            Label,
+        ))
+        .id()
+}
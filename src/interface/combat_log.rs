@@ -0,0 +1,358 @@
+use super::{
+    bar::{PLAYER_CAST_OFFSET, PLAYER_HEIGHT},
+    hud::{Hud, HudAlignment, HudAnchor},
+    TRANSLATION_Z,
+};
+use crate::{
+    combat_log::{CombatLogEvent, CombatLogEventKind},
+    cursor::WorldCursor,
+    input_map::InputAction,
+    player::Player,
+    AppState,
+};
+use bevy::{input::mouse::MouseWheel, prelude::*};
+use std::collections::VecDeque;
+
+const WIDTH: f32 = 160.0;
+const LINE_COUNT: usize = 5;
+const LINE_HEIGHT: f32 = 10.0;
+const PADDING: f32 = 4.0;
+const HEIGHT: f32 = PADDING * 2.0 + LINE_HEIGHT * (LINE_COUNT + 1) as f32;
+const MARGIN: f32 = 8.0;
+/// Above the player's cast bar, clear of it and the action bar.
+const OFFSET_Y: f32 = PLAYER_CAST_OFFSET + PLAYER_HEIGHT / 2.0 + PADDING;
+const HISTORY_CAPACITY: usize = 200;
+
+const FONT_PATH: &str = "fonts/04b03.ttf";
+const FONT_SIZE: f32 = 8.0;
+
+const BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.5);
+const TEXT_COLOR: Color = Color::WHITE;
+const CAST_COLOR: Color = Color::rgb(1.0, 240.0 / 255.0, 0.0);
+const DAMAGE_COLOR: Color = Color::rgb(231.0 / 255.0, 39.0 / 255.0, 37.0 / 255.0);
+const HEAL_COLOR: Color = Color::rgb(0.0, 231.0 / 255.0, 0.0);
+const AURA_COLOR: Color = Color::rgb(0.7, 0.5, 1.0);
+const DEATH_COLOR: Color = Color::GRAY;
+
+/// Combat log line, described once recorded so that it outlives the entities involved.
+struct CombatLogEntry {
+    text: String,
+    color: Color,
+    is_damage: bool,
+    /// Whether the player is the source or the target.
+    is_own: bool,
+}
+
+/// Resource to store recorded combat log lines, newest last, and how they are viewed.
+#[derive(Resource, Default)]
+struct CombatLog {
+    entries: VecDeque<CombatLogEntry>,
+    /// Lines scrolled up from the newest one.
+    scroll: usize,
+    is_damage_only: bool,
+    is_own_only: bool,
+}
+
+impl CombatLog {
+    fn is_shown(&self, entry: &CombatLogEntry) -> bool {
+        (!self.is_damage_only || entry.is_damage) && (!self.is_own_only || entry.is_own)
+    }
+
+    fn shown_entries(&self) -> Vec<&CombatLogEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| self.is_shown(entry))
+            .collect()
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.shown_entries().len().saturating_sub(LINE_COUNT)
+    }
+}
+
+#[derive(Component)]
+struct CombatLogPanel;
+
+#[derive(Component)]
+struct CombatLogHeader;
+
+#[derive(Component)]
+struct CombatLogLines;
+
+pub struct CombatLogPanelPlugin;
+
+impl Plugin for CombatLogPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CombatLog>()
+            .add_system(record_system)
+            .add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn_system))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(input_system)
+                    .with_system(update_system.after(input_system)),
+            )
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+fn record_system(
+    mut combat_log_event_reader: EventReader<CombatLogEvent>,
+    mut combat_log: ResMut<CombatLog>,
+    name_query: Query<&Name>,
+    player_query: Query<Entity, With<Player>>,
+) {
+    let player_entity = player_query.get_single().ok();
+    let name = |entity: Option<Entity>| {
+        entity
+            .and_then(|entity| name_query.get(entity).ok())
+            .map_or_else(|| "Unknown".to_string(), |name| name.to_string())
+    };
+
+    for combat_log_event in combat_log_event_reader.iter() {
+        let source = name(Some(combat_log_event.source));
+        let target = name(combat_log_event.target);
+
+        let (text, color) = match &combat_log_event.kind {
+            CombatLogEventKind::CastStarted(ability_name) => (
+                format!("{source} begins casting {ability_name}."),
+                CAST_COLOR,
+            ),
+            CombatLogEventKind::CastSucceeded(ability_name) => {
+                (format!("{source} casts {ability_name}."), CAST_COLOR)
+            }
+            CombatLogEventKind::CastStopped(ability_name) => (
+                format!("{source} stops casting {ability_name}."),
+                CAST_COLOR,
+            ),
+            CombatLogEventKind::CastInterrupted(ability_name) => (
+                format!("{source}'s {ability_name} is interrupted."),
+                CAST_COLOR,
+            ),
+            CombatLogEventKind::CastFailed(ability_name, reason) => (
+                format!("{source}'s {ability_name} fails: {reason}"),
+                CAST_COLOR,
+            ),
+            CombatLogEventKind::Damage(points, false) => (
+                format!("{source} hits {target} for {points}."),
+                DAMAGE_COLOR,
+            ),
+            CombatLogEventKind::Damage(points, true) => (
+                format!("{source} crits {target} for {points}!"),
+                DAMAGE_COLOR,
+            ),
+            CombatLogEventKind::Heal(points, false) => {
+                (format!("{source} heals {target} for {points}."), HEAL_COLOR)
+            }
+            CombatLogEventKind::Heal(points, true) => (
+                format!("{source} crit heals {target} for {points}!"),
+                HEAL_COLOR,
+            ),
+            CombatLogEventKind::AuraApplied(aura_name) => {
+                (format!("{target} gains {aura_name}."), AURA_COLOR)
+            }
+            CombatLogEventKind::AuraRemoved(aura_name) => {
+                (format!("{aura_name} is removed from {target}."), AURA_COLOR)
+            }
+            CombatLogEventKind::AuraExpired(aura_name) => {
+                (format!("{aura_name} fades from {target}."), AURA_COLOR)
+            }
+            CombatLogEventKind::Died => (format!("{target} dies."), DEATH_COLOR),
+        };
+
+        let entry = CombatLogEntry {
+            text: format!("{:.1} {text}", combat_log_event.time),
+            color,
+            is_damage: matches!(combat_log_event.kind, CombatLogEventKind::Damage(_, _)),
+            is_own: match player_entity {
+                Some(player_entity) => {
+                    combat_log_event.source == player_entity
+                        || combat_log_event.target == Some(player_entity)
+                }
+                None => false,
+            },
+        };
+
+        // Keep the lines in view while scrolled up.
+        if combat_log.scroll > 0 && combat_log.is_shown(&entry) {
+            combat_log.scroll += 1;
+        }
+
+        combat_log.entries.push_back(entry);
+        if combat_log.entries.len() > HISTORY_CAPACITY {
+            combat_log.entries.pop_front();
+        }
+
+        combat_log.scroll = combat_log.scroll.min(combat_log.max_scroll());
+    }
+}
+
+fn spawn_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    hud_query: Query<Entity, With<Hud>>,
+) {
+    let text_style = TextStyle {
+        font: asset_server.load(FONT_PATH),
+        font_size: FONT_SIZE,
+        color: TEXT_COLOR,
+    };
+
+    let entity = commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(WIDTH, HEIGHT)),
+                    color: BACKGROUND_COLOR,
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, 0.0, TRANSLATION_Z),
+                ..default()
+            },
+            HudAnchor::new(
+                HudAlignment::BottomLeft,
+                Vec2::new(WIDTH / 2.0 + MARGIN, HEIGHT / 2.0 + OFFSET_Y),
+            ),
+            CombatLogPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section("", text_style.clone())
+                        .with_alignment(TextAlignment::TOP_LEFT),
+                    transform: Transform::from_xyz(
+                        -WIDTH / 2.0 + PADDING,
+                        HEIGHT / 2.0 - PADDING,
+                        1.0,
+                    ),
+                    ..default()
+                },
+                CombatLogHeader,
+            ));
+
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_sections(
+                        (0..LINE_COUNT).map(|_| TextSection::new("", text_style.clone())),
+                    )
+                    .with_alignment(TextAlignment::TOP_LEFT),
+                    transform: Transform::from_xyz(
+                        -WIDTH / 2.0 + PADDING,
+                        HEIGHT / 2.0 - PADDING - LINE_HEIGHT,
+                        1.0,
+                    ),
+                    ..default()
+                },
+                CombatLogLines,
+            ));
+        })
+        .id();
+
+    commands.entity(hud_query.single()).add_child(entity);
+}
+
+fn input_system(
+    action_input: Res<Input<InputAction>>,
+    world_cursor: Res<WorldCursor>,
+    mut mouse_wheel_event_reader: EventReader<MouseWheel>,
+    mut combat_log: ResMut<CombatLog>,
+    query: Query<&GlobalTransform, With<CombatLogPanel>>,
+) {
+    if action_input.just_pressed(InputAction::ToggleCombatLogDamageOnly) {
+        combat_log.is_damage_only = !combat_log.is_damage_only;
+        combat_log.scroll = 0;
+    }
+
+    if action_input.just_pressed(InputAction::ToggleCombatLogOwnOnly) {
+        combat_log.is_own_only = !combat_log.is_own_only;
+        combat_log.scroll = 0;
+    }
+
+    let is_hovered = match (query.get_single(), world_cursor.position) {
+        (Ok(global_transform), Some(cursor_position)) => {
+            let offset = (cursor_position - global_transform.translation().truncate()).abs();
+
+            offset.x <= WIDTH / 2.0 && offset.y <= HEIGHT / 2.0
+        }
+        _ => false,
+    };
+
+    let mut scroll_lines = 0;
+    for mouse_wheel in mouse_wheel_event_reader.iter() {
+        // Horizontal scrolling doesn't move the log.
+        if is_hovered && mouse_wheel.y != 0.0 {
+            scroll_lines += mouse_wheel.y.signum() as isize;
+        }
+    }
+
+    if action_input.just_pressed(InputAction::ScrollCombatLogUp) {
+        scroll_lines += 1;
+    }
+
+    if action_input.just_pressed(InputAction::ScrollCombatLogDown) {
+        scroll_lines -= 1;
+    }
+
+    if scroll_lines != 0 {
+        let max_scroll = combat_log.max_scroll();
+        combat_log.scroll = combat_log
+            .scroll
+            .saturating_add_signed(scroll_lines)
+            .min(max_scroll);
+    }
+}
+
+fn update_system(
+    combat_log: Res<CombatLog>,
+    mut header_query: Query<(&mut Text, ChangeTrackers<CombatLogHeader>), Without<CombatLogLines>>,
+    mut lines_query: Query<(&mut Text, ChangeTrackers<CombatLogLines>), Without<CombatLogHeader>>,
+) {
+    for (mut text, change_trackers) in header_query.iter_mut() {
+        if !combat_log.is_changed() && !change_trackers.is_added() {
+            continue;
+        }
+
+        let mut value = "Combat log".to_string();
+        if combat_log.is_damage_only {
+            value.push_str(" [damage]");
+        }
+
+        if combat_log.is_own_only {
+            value.push_str(" [mine]");
+        }
+
+        if combat_log.scroll > 0 {
+            value.push_str(&format!(" [-{}]", combat_log.scroll));
+        }
+
+        text.sections[0].value = value;
+    }
+
+    for (mut text, change_trackers) in lines_query.iter_mut() {
+        if !combat_log.is_changed() && !change_trackers.is_added() {
+            continue;
+        }
+
+        let shown_entries = combat_log.shown_entries();
+        let end = shown_entries.len() - combat_log.scroll.min(shown_entries.len());
+        let start = end.saturating_sub(LINE_COUNT);
+
+        for (index, section) in text.sections.iter_mut().enumerate() {
+            match shown_entries
+                .get(start + index)
+                .filter(|_| start + index < end)
+            {
+                Some(entry) => {
+                    section.value = format!("{}\n", entry.text);
+                    section.style.color = entry.color;
+                }
+                None => section.value = String::new(),
+            }
+        }
+    }
+}
+
+fn despawn_system(mut commands: Commands, query: Query<Entity, With<CombatLogPanel>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
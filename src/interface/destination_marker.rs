@@ -0,0 +1,67 @@
+use crate::{navigation::Path, player::Player, AppState};
+use bevy::prelude::*;
+
+const SIZE: f32 = 4.0;
+const COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.5);
+/// Depth between tiles and creatures.
+const TRANSLATION_Z: f32 = 0.5;
+
+/// Component to mark where the player is moving to.
+#[derive(Component)]
+struct DestinationMarker;
+
+pub struct DestinationMarkerPlugin;
+
+impl Plugin for DestinationMarkerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_update(AppState::Game).with_system(update_system))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+fn update_system(
+    mut commands: Commands,
+    player_query: Query<Option<&Path>, With<Player>>,
+    mut query: Query<(Entity, &mut Transform), With<DestinationMarker>>,
+) {
+    let path = match player_query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    let destination = match path {
+        Some(path) => path.destination,
+        None => {
+            for (entity, _) in query.iter() {
+                commands.entity(entity).despawn();
+            }
+
+            return;
+        }
+    };
+
+    let translation = destination.extend(TRANSLATION_Z);
+    match query.get_single_mut() {
+        Ok((_, mut transform)) => transform.translation = translation,
+        Err(_) => {
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: COLOR,
+                        custom_size: Some(Vec2::splat(SIZE)),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(translation),
+                    ..default()
+                },
+                DestinationMarker,
+            ));
+        }
+    }
+}
+
+fn despawn_system(mut commands: Commands, query: Query<Entity, With<DestinationMarker>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
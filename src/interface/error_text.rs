@@ -0,0 +1,193 @@
+use super::{
+    hud::{Hud, HudAlignment, HudAnchor},
+    TRANSLATION_Z,
+};
+use crate::{
+    ability::{PerformAbilityFailed, TryAbilityFailed},
+    ability_registry::AbilityRegistry,
+    player::Player,
+    AppState,
+};
+use bevy::prelude::*;
+
+const FONT_PATH: &str = "fonts/04b03.ttf";
+const FONT_SIZE: f32 = 12.0;
+const COLOR: Color = Color::rgb(231.0 / 255.0, 39.0 / 255.0, 37.0 / 255.0);
+
+const OFFSET_Y: f32 = -56.0;
+const LINE_HEIGHT: f32 = 14.0;
+const MAX_COUNT: usize = 3;
+
+const DURATION: f32 = 2.0;
+/// Fraction of the duration after which the text starts fading out.
+const FADE_START: f32 = 0.5;
+
+/// Component for a message explaining why one of the player's actions was rejected. Repeated messages restart the
+/// one already shown instead of stacking.
+#[derive(Component)]
+struct ErrorText {
+    message: String,
+    duration_timer: Timer,
+}
+
+impl ErrorText {
+    fn new(message: String) -> Self {
+        Self {
+            message,
+            duration_timer: Timer::from_seconds(DURATION, TimerMode::Once),
+        }
+    }
+}
+
+pub struct ErrorTextPlugin;
+
+impl Plugin for ErrorTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(AppState::Game)
+                .with_system(spawn_system)
+                .with_system(fade_system)
+                .with_system(layout_system.after(spawn_system).after(fade_system)),
+        )
+        .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spawn_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    ability_registry: Res<AbilityRegistry>,
+    mut try_ability_failed_event_reader: EventReader<TryAbilityFailed>,
+    mut perform_ability_failed_event_reader: EventReader<PerformAbilityFailed>,
+    player_query: Query<Entity, With<Player>>,
+    hud_query: Query<Entity, With<Hud>>,
+    mut query: Query<&mut ErrorText>,
+) {
+    let player_entity = match player_query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    // Name the ability, and whether it was rejected right away or failed once its cast was over.
+    let ability_name = |ability_id| {
+        ability_registry
+            .get(ability_id)
+            .map_or_else(|| "Unknown".to_string(), |ability| ability.name.clone())
+    };
+    let try_messages = try_ability_failed_event_reader
+        .iter()
+        .filter(|try_ability_failed| try_ability_failed.source == player_entity)
+        .map(|try_ability_failed| {
+            format!(
+                "{}: {}",
+                ability_name(try_ability_failed.ability_id),
+                try_ability_failed.reason
+            )
+        });
+    let perform_messages = perform_ability_failed_event_reader
+        .iter()
+        .filter(|perform_ability_failed| perform_ability_failed.source == player_entity)
+        .map(|perform_ability_failed| {
+            format!(
+                "{} failed: {}",
+                ability_name(perform_ability_failed.ability_id),
+                perform_ability_failed.reason
+            )
+        });
+
+    let mut messages = Vec::new();
+    for message in try_messages.chain(perform_messages) {
+        if !messages.contains(&message) {
+            messages.push(message);
+        }
+    }
+
+    for message in messages {
+        if let Some(mut error_text) = query
+            .iter_mut()
+            .find(|error_text| error_text.message == message)
+        {
+            error_text.duration_timer.reset();
+
+            continue;
+        }
+
+        let text_style = TextStyle {
+            font: asset_server.load(FONT_PATH),
+            font_size: FONT_SIZE,
+            color: COLOR,
+        };
+
+        let entity = commands
+            .spawn((
+                Text2dBundle {
+                    text: Text::from_section(message.clone(), text_style)
+                        .with_alignment(TextAlignment::CENTER),
+                    transform: Transform::from_translation(Vec3::new(0.0, 0.0, TRANSLATION_Z)),
+                    ..default()
+                },
+                HudAnchor::new(HudAlignment::Top, Vec2::new(0.0, OFFSET_Y)),
+                ErrorText::new(message),
+            ))
+            .id();
+
+        commands.entity(hud_query.single()).add_child(entity);
+    }
+}
+
+fn fade_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Text, &mut ErrorText)>,
+) {
+    for (entity, mut text, mut error_text) in query.iter_mut() {
+        error_text.duration_timer.tick(time.delta());
+        if error_text.duration_timer.finished() {
+            commands.entity(entity).despawn_recursive();
+
+            continue;
+        }
+
+        let percent = error_text.duration_timer.percent();
+        if percent > FADE_START {
+            let color_alpha = 1.0 - (percent - FADE_START) / (1.0 - FADE_START);
+            text.sections[0].style.color.set_a(color_alpha);
+        } else {
+            text.sections[0].style.color.set_a(1.0);
+        }
+    }
+}
+
+/// Stacks messages downwards from the newest one, dropping the oldest ones past the limit.
+fn layout_system(mut commands: Commands, mut query: Query<(Entity, &mut HudAnchor, &ErrorText)>) {
+    let mut error_texts = query
+        .iter_mut()
+        .filter(|(_, _, error_text)| !error_text.duration_timer.finished())
+        .collect::<Vec<_>>();
+    error_texts.sort_by(|(_, _, error_text), (_, _, other_error_text)| {
+        error_text
+            .duration_timer
+            .elapsed()
+            .cmp(&other_error_text.duration_timer.elapsed())
+    });
+
+    for (index, (entity, hud_anchor, _)) in error_texts.iter_mut().enumerate() {
+        if index >= MAX_COUNT {
+            commands.entity(*entity).despawn_recursive();
+
+            continue;
+        }
+
+        let offset = Vec2::new(0.0, OFFSET_Y - index as f32 * LINE_HEIGHT);
+        if hud_anchor.offset != offset {
+            hud_anchor.offset = offset;
+        }
+    }
+}
+
+fn despawn_system(mut commands: Commands, query: Query<Entity, With<ErrorText>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,89 @@
+use crate::camera::Viewport;
+use bevy::{prelude::*, transform::TransformSystem};
+
+/// Component for the root entity of screen-space interface elements, kept in place with the camera.
+#[derive(Component)]
+pub struct Hud;
+
+/// Component to position an interface element relative to a point on the screen's edges.
+#[derive(Component)]
+pub struct HudAnchor {
+    pub alignment: HudAlignment,
+    pub offset: Vec2,
+}
+
+impl HudAnchor {
+    pub fn new(alignment: HudAlignment, offset: Vec2) -> Self {
+        Self { alignment, offset }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum HudAlignment {
+    TopLeft,
+    Top,
+    TopRight,
+    BottomLeft,
+    Bottom,
+}
+
+impl HudAlignment {
+    /// Returns the position relative to the screen's center, in fractions of the screen's size.
+    fn position(&self) -> Vec2 {
+        match self {
+            Self::TopLeft => Vec2::new(-0.5, 0.5),
+            Self::Top => Vec2::new(0.0, 0.5),
+            Self::TopRight => Vec2::new(0.5, 0.5),
+            Self::BottomLeft => Vec2::new(-0.5, -0.5),
+            Self::Bottom => Vec2::new(0.0, -0.5),
+        }
+    }
+}
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(spawn_system)
+            .add_system(layout_system)
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                follow_camera_system.before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+fn spawn_system(mut commands: Commands) {
+    commands.spawn((SpatialBundle::default(), Hud));
+}
+
+fn layout_system(
+    viewport: Res<Viewport>,
+    mut query: Query<(&mut Transform, &HudAnchor, ChangeTrackers<HudAnchor>)>,
+) {
+    for (mut transform, hud_anchor, hud_anchor_change_trackers) in query.iter_mut() {
+        if !viewport.is_changed() && !hud_anchor_change_trackers.is_changed() {
+            continue;
+        }
+
+        let position =
+            (hud_anchor.alignment.position() * viewport.size + hud_anchor.offset).round();
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+fn follow_camera_system(
+    camera_query: Query<&Transform, (With<Camera2d>, Without<Hud>)>,
+    mut hud_query: Query<&mut Transform, With<Hud>>,
+) {
+    let camera_transform = match camera_query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    for mut transform in hud_query.iter_mut() {
+        transform.translation.x = camera_transform.translation.x;
+        transform.translation.y = camera_transform.translation.y;
+    }
+}
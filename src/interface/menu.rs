@@ -1,4 +1,4 @@
-use crate::{level::LevelResult, AppState};
+use crate::{input_map::InputAction, level::LevelResult, AppState};
 use bevy::prelude::*;
 
 const FONT_PATH: &str = "fonts/04b03.ttf";
@@ -12,9 +12,7 @@ pub struct MenuPlugin;
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_system_set(SystemSet::on_enter(AppState::Menu).with_system(spawn_system))
-            .add_system_set(
-                SystemSet::on_update(AppState::Menu).with_system(handle_keyboard_input_system),
-            )
+            .add_system_set(SystemSet::on_update(AppState::Menu).with_system(handle_input_system))
             .add_system_set(SystemSet::on_exit(AppState::Menu).with_system(despawn_system));
     }
 }
@@ -32,8 +30,8 @@ fn spawn_system(
 
     let text_value = match *level_result {
         LevelResult::None => "Press Return to start.",
-        LevelResult::Won => "Not bad, press Return to restart.",
-        LevelResult::Lost => "Wrecked! Press Return to restart.",
+        LevelResult::Won => "All levels cleared, press Return to restart.",
+        LevelResult::Lost => "Wrecked! Press Return to retry the level.",
     };
 
     commands.spawn((
@@ -52,11 +50,11 @@ fn despawn_system(mut commands: Commands, query: Query<Entity, With<Menu>>) {
     }
 }
 
-fn handle_keyboard_input_system(
-    keyboard_input: Res<Input<KeyCode>>,
+fn handle_input_system(
+    action_input: Res<Input<InputAction>>,
     mut app_state: ResMut<State<AppState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Return) {
+    if action_input.just_pressed(InputAction::Confirm) {
         app_state.set(AppState::Game).unwrap();
     }
 }
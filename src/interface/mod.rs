@@ -1,19 +1,31 @@
+mod action_bar;
+mod aura;
 mod bar;
-mod easing;
+mod combat_log;
+mod destination_marker;
+pub mod easing;
+mod error_text;
 mod floating_text;
+mod hud;
 mod menu;
+mod reticle;
+mod target_frame;
 mod target_indicator;
 
-use crate::{CAMERA_SCALE, WINDOW_HEIGHT, WINDOW_WIDTH};
+use action_bar::ActionBarPlugin;
+use aura::AuraPlugin;
 use bar::BarPlugin;
 use bevy::app::{PluginGroup, PluginGroupBuilder};
+use combat_log::CombatLogPanelPlugin;
+use destination_marker::DestinationMarkerPlugin;
+use error_text::ErrorTextPlugin;
 use floating_text::FloatingTextPlugin;
+use hud::HudPlugin;
 use menu::MenuPlugin;
+use reticle::ReticlePlugin;
+use target_frame::TargetFramePlugin;
 use target_indicator::TargetIndicatorPlugin;
 
-const WIDTH: f32 = WINDOW_WIDTH * CAMERA_SCALE;
-const HEIGHT: f32 = WINDOW_HEIGHT * CAMERA_SCALE;
-
 const TRANSLATION_Z: f32 = 50.0;
 
 pub struct InterfacePlugins;
@@ -21,9 +33,17 @@ pub struct InterfacePlugins;
 impl PluginGroup for InterfacePlugins {
     fn build(self) -> PluginGroupBuilder {
         PluginGroupBuilder::start::<Self>()
+            .add(ActionBarPlugin)
+            .add(AuraPlugin)
             .add(BarPlugin)
+            .add(CombatLogPanelPlugin)
+            .add(DestinationMarkerPlugin)
+            .add(ErrorTextPlugin)
             .add(FloatingTextPlugin)
+            .add(HudPlugin)
             .add(MenuPlugin)
+            .add(ReticlePlugin)
+            .add(TargetFramePlugin)
             .add(TargetIndicatorPlugin)
     }
 }
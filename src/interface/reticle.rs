@@ -0,0 +1,95 @@
+use crate::{
+    ability::verify_target_position, cursor::WorldCursor, level::Obstacle, player::Player,
+    spellbook::AbilityPlacement, AppState,
+};
+use bevy::{
+    prelude::*,
+    sprite::{MaterialMesh2dBundle, Mesh2dHandle},
+};
+
+const COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.25);
+const OUT_OF_RANGE_COLOR: Color = Color::rgba(1.0, 0.2, 0.2, 0.25);
+/// Depth between tiles and creatures, above the destination marker.
+const TRANSLATION_Z: f32 = 0.6;
+
+/// Component to preview where the player is placing an ability.
+#[derive(Component)]
+struct Reticle;
+
+pub struct ReticlePlugin;
+
+impl Plugin for ReticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_update(AppState::Game).with_system(update_system))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    world_cursor: Res<WorldCursor>,
+    player_query: Query<(&Transform, Option<&AbilityPlacement>), With<Player>>,
+    mut query: Query<
+        (Entity, &mut Transform, &Handle<ColorMaterial>),
+        (With<Reticle>, Without<Player>, Without<Obstacle>),
+    >,
+    obstacle_query: Query<&Transform, With<Obstacle>>,
+) {
+    let (player_transform, ability_placement) = match player_query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    let (ability_placement, cursor_position) = match (ability_placement, world_cursor.position) {
+        (Some(ability_placement), Some(cursor_position)) => (ability_placement, cursor_position),
+        _ => {
+            for (entity, _, _) in query.iter() {
+                commands.entity(entity).despawn();
+            }
+
+            return;
+        }
+    };
+
+    let ability = &ability_placement.ability;
+    let color = match verify_target_position(
+        player_transform.translation.truncate(),
+        cursor_position,
+        ability.range,
+        &obstacle_query,
+    ) {
+        Ok(_) => COLOR,
+        Err(_) => OUT_OF_RANGE_COLOR,
+    };
+
+    let transform = Transform::from_translation(cursor_position.extend(TRANSLATION_Z))
+        .with_scale(Vec2::splat(ability.ground_radius().unwrap_or_default()).extend(1.0));
+    match query.get_single_mut() {
+        Ok((_, mut reticle_transform, material)) => {
+            *reticle_transform = transform;
+            if let Some(material) = materials.get_mut(material) {
+                material.color = color;
+            }
+        }
+        Err(_) => {
+            commands.spawn((
+                MaterialMesh2dBundle {
+                    mesh: Mesh2dHandle(meshes.add(shape::Circle::new(1.0).into())),
+                    material: materials.add(ColorMaterial::from(color)),
+                    transform,
+                    ..default()
+                },
+                Reticle,
+            ));
+        }
+    }
+}
+
+fn despawn_system(mut commands: Commands, query: Query<Entity, With<Reticle>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,119 @@
+use super::{
+    bar::{
+        spawn, spawn_label, CastBar, HealthBar, ManaBar, CAST_COLOR, FONT_PATH, FONT_SIZE,
+        HEALTH_COLOR, MANA_COLOR, PLAYER_HEIGHT, PLAYER_MARGIN, PLAYER_WIDTH,
+    },
+    hud::{Hud, HudAlignment, HudAnchor},
+    TRANSLATION_Z,
+};
+use crate::{player::PlayerTargetChanged, AppState};
+use bevy::prelude::*;
+
+const NAME_HEIGHT: f32 = 12.0;
+const SPACING: f32 = 4.0;
+
+/// Component for the frame summarizing the player's target, mirroring the player's bars on the other side.
+#[derive(Component)]
+struct TargetFrame;
+
+pub struct TargetFramePlugin;
+
+impl Plugin for TargetFramePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_update(AppState::Game).with_system(spawn_system))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+fn spawn_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut player_target_changed_event_reader: EventReader<PlayerTargetChanged>,
+    query: Query<Entity, With<TargetFrame>>,
+    name_query: Query<&Name>,
+    hud_query: Query<Entity, With<Hud>>,
+) {
+    let player_target_changed = match player_target_changed_event_reader.iter().last() {
+        Some(result) => result,
+        None => return,
+    };
+
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let target_entity = match player_target_changed.target_entity {
+        Some(result) => result,
+        None => return,
+    };
+
+    let font = asset_server.load(FONT_PATH);
+    let size = Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT);
+
+    let name = name_query
+        .get(target_entity)
+        .map_or_else(|_| String::new(), |name| name.to_string());
+    let name_entity = spawn_label(
+        name,
+        font.clone(),
+        FONT_SIZE,
+        Vec3::new(0.0, -NAME_HEIGHT / 2.0, 0.0),
+        &mut commands,
+    );
+
+    let bar_y =
+        |index: f32| -NAME_HEIGHT - SPACING - PLAYER_HEIGHT * (index + 0.5) - SPACING * index;
+    let health_entity = spawn(
+        HEALTH_COLOR,
+        Vec3::new(0.0, bar_y(0.0), 0.0),
+        size,
+        true,
+        HealthBar {
+            entity: target_entity,
+        },
+        &mut commands,
+        Some(font.clone()),
+    );
+    let mana_entity = spawn(
+        MANA_COLOR,
+        Vec3::new(0.0, bar_y(1.0), 0.0),
+        size,
+        true,
+        ManaBar {
+            entity: target_entity,
+        },
+        &mut commands,
+        Some(font.clone()),
+    );
+    let cast_entity = spawn(
+        CAST_COLOR,
+        Vec3::new(0.0, bar_y(2.0), 0.0),
+        size,
+        false,
+        CastBar {
+            entity: target_entity,
+        },
+        &mut commands,
+        Some(font),
+    );
+
+    let entity = commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(0.0, 0.0, TRANSLATION_Z)),
+            HudAnchor::new(
+                HudAlignment::TopRight,
+                Vec2::new(-PLAYER_WIDTH * 0.5 - PLAYER_MARGIN, -PLAYER_MARGIN),
+            ),
+            TargetFrame,
+        ))
+        .push_children(&[name_entity, health_entity, mana_entity, cast_entity])
+        .id();
+
+    commands.entity(hud_query.single()).add_child(entity);
+}
+
+fn despawn_system(mut commands: Commands, query: Query<Entity, With<TargetFrame>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
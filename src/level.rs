@@ -1,18 +1,38 @@
 use crate::{
+    ai::Ai,
     creature::{Creature, CreatureBundle},
     enemy::Enemy,
     health::Health,
+    mana::Mana,
     player::Player,
+    spellbook::{ActionBar, Spellbook},
     sprite::Sprite,
-    zone::Zone,
+    threat::ThreatTable,
+    zone::{generator::ZoneGenerator, Zone},
     AppState,
 };
 use bevy::prelude::*;
 
-const PLAYER_TRANSLATION: (f32, f32, f32) = (-80.0, 0.0, 0.0);
-const GOBLIN_TRANSLATIONS: [(f32, f32, f32); 2] = [(80.0, 30.0, 0.0), (80.0, -30.0, 0.0)];
-const ZONE_COLUMNS: usize = 22;
-const ZONE_ROWS: usize = 16;
+/// Zones of the campaign's levels, in order.
+const LEVEL_ZONES: [LevelZone; 3] = [
+    LevelZone::Map("arena.zone.ron"),
+    LevelZone::Generated(3),
+    LevelZone::Generated(11),
+];
+/// Depth between tile layers, kept below creatures.
+const TILE_LAYER_Z: f32 = 0.01;
+const CREATURE_Z: f32 = 1.0;
+const PLAYER_NAME: &str = "Player";
+const PLAYER_HEALTH_POINTS: u16 = 160;
+const PLAYER_MANA_POINTS: u16 = 100;
+const PLAYER_ABILITY_IDS: [u8; 8] = [0, 1, 2, 3, 5, 6, 7, 8];
+
+enum LevelZone {
+    /// Zone loaded from a map file.
+    Map(&'static str),
+    /// Zone generated from a seed.
+    Generated(u64),
+}
 
 /// Resource to keep track of the level's result. Set to `LevelResult::None` while the level is in progress.
 #[derive(Resource)]
@@ -22,6 +42,49 @@ pub enum LevelResult {
     Lost,
 }
 
+/// Resource to store the index of the level in progress, or of the last level played.
+#[derive(Resource, Default)]
+pub struct CurrentLevel {
+    pub index: usize,
+}
+
+/// Resource to store handles to the zones of each level, which need to be loaded before the campaign can start.
+#[derive(Resource, Default)]
+struct LevelAssets {
+    zones: Vec<Handle<Zone>>,
+}
+
+/// Player state carried over from one level to the next.
+#[derive(Clone)]
+struct PlayerState {
+    health_points: u16,
+    mana_points: u16,
+    spellbook: Spellbook,
+    action_bar: ActionBar,
+}
+
+impl Default for PlayerState {
+    fn default() -> Self {
+        let spellbook = Spellbook::new(&PLAYER_ABILITY_IDS);
+
+        Self {
+            health_points: PLAYER_HEALTH_POINTS,
+            mana_points: PLAYER_MANA_POINTS,
+            action_bar: ActionBar::from_spellbook(&spellbook),
+            spellbook,
+        }
+    }
+}
+
+/// Resource to store the player's state at the start of the current level, restored with full health and mana when
+/// the level is restarted.
+#[derive(Resource, Default)]
+struct Checkpoint {
+    player_state: PlayerState,
+    /// State carried over from the previous level, used only when the level is first entered.
+    carried_over_player_state: Option<PlayerState>,
+}
+
 #[derive(Component)]
 struct Tile;
 
@@ -33,75 +96,163 @@ pub struct LevelPlugin;
 impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(LevelResult::None)
+            .init_resource::<CurrentLevel>()
+            .init_resource::<LevelAssets>()
+            .init_resource::<Checkpoint>()
+            .add_startup_system(load_system)
+            .add_system_set(SystemSet::on_update(AppState::Loading).with_system(loading_system))
             .add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn_system))
             .add_system_set(SystemSet::on_update(AppState::Game).with_system(end_system))
             .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
     }
 }
 
+fn load_system(
+    asset_server: Res<AssetServer>,
+    mut zones: ResMut<Assets<Zone>>,
+    mut level_assets: ResMut<LevelAssets>,
+) {
+    let zone_generator = ZoneGenerator::default();
+    level_assets.zones = LEVEL_ZONES
+        .iter()
+        .map(|level_zone| match level_zone {
+            LevelZone::Map(path) => asset_server.load(*path),
+            LevelZone::Generated(seed) => zones.add(
+                zone_generator
+                    .generate(*seed)
+                    .unwrap_or_else(|error| panic!("Zone seed ({seed}) failed: {error}")),
+            ),
+        })
+        .collect();
+}
+
+fn loading_system(
+    zones: Res<Assets<Zone>>,
+    mut app_state: ResMut<State<AppState>>,
+    level_assets: Res<LevelAssets>,
+) {
+    // Invalid files are reported by the asset server and picked up again once fixed.
+    if level_assets
+        .zones
+        .iter()
+        .all(|zone| zones.get(zone).is_some())
+    {
+        app_state.set(AppState::Game).unwrap();
+    }
+}
+
 fn spawn_system(
     mut commands: Commands,
     texture_atlases: Res<Assets<TextureAtlas>>,
+    zones: Res<Assets<Zone>>,
+    level_assets: Res<LevelAssets>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut checkpoint: ResMut<Checkpoint>,
     mut level_result: ResMut<LevelResult>,
 ) {
+    // Start the campaign over once it is completed, otherwise resume from the checkpoint.
+    if let LevelResult::Won = *level_result {
+        *current_level = CurrentLevel::default();
+        *checkpoint = Checkpoint::default();
+    }
+
     *level_result = LevelResult::None;
 
-    let zone = Zone::new(ZONE_COLUMNS, ZONE_ROWS);
-    for (x, row_tiles) in zone.tiles.iter().enumerate() {
-        for (y, tile) in row_tiles.iter().enumerate() {
-            if let Some(tile) = tile {
-                let tile_position = zone.tile_position(x, y);
-                let entity = commands
-                    .spawn((
-                        SpriteSheetBundle {
-                            texture_atlas: texture_atlases.get_handle(Sprite::SHEET_PATH),
-                            sprite: TextureAtlasSprite::new(tile.sprite.index()),
-                            transform: Transform::from_translation(tile_position.extend(0.0)),
-                            ..default()
-                        },
-                        Tile,
-                    ))
-                    .id();
-
-                if tile.is_obstructed {
-                    commands.entity(entity).insert(Obstacle);
+    let zone = zones
+        .get(&level_assets.zones[current_level.index])
+        .unwrap()
+        .clone();
+    for (layer_index, layer) in zone.layers.iter().enumerate() {
+        for (x, column_tiles) in layer.iter().enumerate() {
+            for (y, tile) in column_tiles.iter().enumerate() {
+                if let Some(tile) = tile {
+                    let tile_position = zone.tile_position(x, y);
+                    let entity = commands
+                        .spawn((
+                            SpriteSheetBundle {
+                                texture_atlas: texture_atlases.get_handle(Sprite::SHEET_PATH),
+                                sprite: TextureAtlasSprite::new(tile.sprite.index()),
+                                transform: Transform::from_translation(
+                                    tile_position.extend(layer_index as f32 * TILE_LAYER_Z),
+                                ),
+                                ..default()
+                            },
+                            Tile,
+                        ))
+                        .id();
+
+                    if tile.is_obstructed {
+                        commands.entity(entity).insert(Obstacle);
+                    }
                 }
             }
         }
     }
 
-    commands.spawn((
-        CreatureBundle::new(160, 100),
-        Player,
-        SpriteSheetBundle {
-            texture_atlas: texture_atlases.get_handle(Sprite::SHEET_PATH),
-            sprite: TextureAtlasSprite::new(Sprite::Player.index()),
-            transform: Transform::from_translation(PLAYER_TRANSLATION.into()),
-            ..default()
-        },
-    ));
-
-    for goblin_translation in GOBLIN_TRANSLATIONS {
+    let player_position = zone.tile_position(zone.player_spawn.0, zone.player_spawn.1);
+
+    let player_state = checkpoint
+        .carried_over_player_state
+        .take()
+        .unwrap_or_else(|| checkpoint.player_state.clone());
+    commands
+        .spawn((
+            CreatureBundle::new(PLAYER_HEALTH_POINTS, PLAYER_MANA_POINTS),
+            Player,
+            Name::new(PLAYER_NAME),
+            player_state.action_bar,
+            player_state.spellbook,
+            SpriteSheetBundle {
+                texture_atlas: texture_atlases.get_handle(Sprite::SHEET_PATH),
+                sprite: TextureAtlasSprite::new(Sprite::Player.index()),
+                transform: Transform::from_translation(player_position.extend(CREATURE_Z)),
+                ..default()
+            },
+        ))
+        .insert((
+            Health {
+                points: player_state.health_points,
+                max_points: PLAYER_HEALTH_POINTS,
+            },
+            Mana {
+                points: player_state.mana_points,
+                ..Mana::new(PLAYER_MANA_POINTS)
+            },
+        ));
+
+    for enemy_spawn in &zone.enemy_spawns {
+        let enemy_position = zone.tile_position(enemy_spawn.tile.0, enemy_spawn.tile.1);
         commands.spawn((
-            CreatureBundle::new(80, 40),
+            CreatureBundle::new(
+                enemy_spawn.kind.health_points(),
+                enemy_spawn.kind.mana_points(),
+            ),
             Enemy,
+            Name::new(enemy_spawn.kind.name()),
+            enemy_spawn.kind,
+            Ai::new(enemy_spawn.kind.ai_config()),
+            ThreatTable::default(),
             SpriteSheetBundle {
                 texture_atlas: texture_atlases.get_handle(Sprite::SHEET_PATH),
-                sprite: TextureAtlasSprite::new(Sprite::Goblin.index()),
-                transform: Transform::from_translation(goblin_translation.into()),
+                sprite: TextureAtlasSprite::new(enemy_spawn.kind.sprite().index()),
+                transform: Transform::from_translation(enemy_position.extend(CREATURE_Z)),
                 ..default()
             },
         ));
     }
+
+    commands.insert_resource(zone);
 }
 
 fn end_system(
     mut app_state: ResMut<State<AppState>>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut checkpoint: ResMut<Checkpoint>,
     mut level_result: ResMut<LevelResult>,
-    player_query: Query<&Health, With<Player>>,
+    player_query: Query<(&Health, &Mana, &Spellbook, &ActionBar), With<Player>>,
     enemy_query: Query<&Health, With<Enemy>>,
 ) {
-    let player_health = player_query.single();
+    let (player_health, player_mana, spellbook, action_bar) = player_query.single();
     if player_health.points == 0 {
         *level_result = LevelResult::Lost;
         app_state.set(AppState::Menu).unwrap();
@@ -109,8 +260,26 @@ fn end_system(
         .iter()
         .all(|enemy_health| enemy_health.points == 0)
     {
-        *level_result = LevelResult::Won;
-        app_state.set(AppState::Menu).unwrap();
+        if current_level.index + 1 == LEVEL_ZONES.len() {
+            *level_result = LevelResult::Won;
+            app_state.set(AppState::Menu).unwrap();
+
+            return;
+        }
+
+        current_level.index += 1;
+        checkpoint.player_state = PlayerState {
+            health_points: PLAYER_HEALTH_POINTS,
+            mana_points: PLAYER_MANA_POINTS,
+            spellbook: spellbook.clone(),
+            action_bar: action_bar.clone(),
+        };
+        checkpoint.carried_over_player_state = Some(PlayerState {
+            health_points: player_health.points,
+            mana_points: player_mana.points,
+            ..checkpoint.player_state.clone()
+        });
+        app_state.restart().unwrap();
     }
 }
 
@@ -122,4 +291,6 @@ fn despawn_system(
     for entity in tile_query.iter().chain(creature_query.iter()) {
         commands.entity(entity).despawn();
     }
+
+    commands.remove_resource::<Zone>();
 }
@@ -1,35 +1,56 @@
 mod ability;
+mod ability_registry;
+mod ai;
+mod camera;
+mod combat_log;
 mod creature;
 mod critical;
+mod cursor;
 mod effect;
 mod enemy;
 mod health;
+mod input_map;
 mod interface;
 mod intersect_line_aabb;
 mod level;
 mod mana;
+mod navigation;
 mod player;
 mod position;
+mod projectile;
+mod spellbook;
 mod sprite;
 mod target;
+mod threat;
 mod zone;
 
 use ability::AbilityPlugin;
+use ability_registry::AbilityRegistryPlugin;
+use ai::AiPlugin;
 use bevy::prelude::*;
+use camera::CameraPlugin;
+use combat_log::CombatLogPlugin;
+use cursor::CursorPlugin;
 use effect::EffectPlugin;
+use input_map::InputMapPlugin;
 use interface::InterfacePlugins;
 use level::LevelPlugin;
 use mana::ManaPlugin;
+use navigation::NavigationPlugin;
 use player::PlayerPlugin;
 use position::PositionPlugin;
+use projectile::ProjectilePlugin;
+use spellbook::SpellbookPlugin;
 use sprite::Sprite;
+use threat::ThreatPlugin;
+use zone::ZonePlugin;
 
 const WINDOW_WIDTH: f32 = 800.0;
 const WINDOW_HEIGHT: f32 = 600.0;
-const CAMERA_SCALE: f32 = 1.0 / 2.0;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 enum AppState {
+    Loading,
     Menu,
     Game,
 }
@@ -37,7 +58,7 @@ enum AppState {
 fn main() {
     App::new()
         .insert_resource(ClearColor(Color::BLACK))
-        .add_state(AppState::Game)
+        .add_state(AppState::Loading)
         .add_plugins(
             DefaultPlugins
                 .set(WindowPlugin {
@@ -45,29 +66,40 @@ fn main() {
                         title: "Bun".to_string(),
                         width: WINDOW_WIDTH,
                         height: WINDOW_HEIGHT,
-                        resizable: false,
+                        resizable: true,
                         ..default()
                     },
                     ..default()
                 })
+                .set(AssetPlugin {
+                    watch_for_changes: true,
+                    ..default()
+                })
                 .set(ImagePlugin::default_nearest()),
         )
         .add_plugins(InterfacePlugins)
         .add_plugin(AbilityPlugin)
+        .add_plugin(AbilityRegistryPlugin)
+        .add_plugin(AiPlugin)
+        .add_plugin(CameraPlugin)
+        .add_plugin(CombatLogPlugin)
+        .add_plugin(CursorPlugin)
         .add_plugin(EffectPlugin)
+        .add_plugin(InputMapPlugin)
         .add_plugin(LevelPlugin)
         .add_plugin(ManaPlugin)
+        .add_plugin(NavigationPlugin)
         .add_plugin(PositionPlugin)
         .add_plugin(PlayerPlugin)
+        .add_plugin(ProjectilePlugin)
+        .add_plugin(SpellbookPlugin)
+        .add_plugin(ThreatPlugin)
+        .add_plugin(ZonePlugin)
         .add_startup_system(setup_system)
         .run();
 }
 
-fn setup_system(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-) {
+fn setup_system(asset_server: Res<AssetServer>, mut texture_atlases: ResMut<Assets<TextureAtlas>>) {
     let sprite_sheet_image_handle = asset_server.load(Sprite::SHEET_PATH);
     let texture_atlas = TextureAtlas::from_grid(
         sprite_sheet_image_handle,
@@ -79,8 +111,4 @@ fn setup_system(
     );
     // Use path as handle identifier.
     let _ = texture_atlases.set(Sprite::SHEET_PATH, texture_atlas);
-
-    let mut camera_bundle = Camera2dBundle::default();
-    camera_bundle.projection.scale = CAMERA_SCALE;
-    commands.spawn(camera_bundle);
 }
@@ -0,0 +1,170 @@
+use crate::{
+    creature::{Creature, CREATURE_SPEED},
+    position::ChangePosition,
+    zone::Zone,
+    AppState,
+};
+use bevy::prelude::*;
+
+/// Size of creatures in tiles, when finding paths.
+const CREATURE_TILE_SIZE: usize = 1;
+/// Duration without getting closer to the next waypoint before planning the path again.
+const BLOCKED_DURATION: f32 = 0.5;
+/// Distance to a waypoint to consider it reached. Steps are shortened near waypoints so they are not overshot.
+const ARRIVAL_DISTANCE: f32 = 0.5;
+
+/// Event to move a creature to a destination, finding a path around obstacles.
+pub struct MoveTo {
+    pub entity: Entity,
+    pub destination: Vec2,
+}
+
+/// Component to store waypoints a creature is moving along, removed once the destination is reached.
+#[derive(Component)]
+pub struct Path {
+    pub destination: Vec2,
+    waypoints: Vec<Vec2>,
+    closest_distance: f32,
+    blocked_timer: Timer,
+}
+
+impl Path {
+    fn new(destination: Vec2, waypoints: Vec<Vec2>) -> Self {
+        Self {
+            destination,
+            waypoints,
+            closest_distance: f32::INFINITY,
+            blocked_timer: Timer::from_seconds(BLOCKED_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MoveTo>().add_system_set(
+            SystemSet::on_update(AppState::Game)
+                .with_system(move_to_system)
+                .with_system(follow_path_system.after(move_to_system)),
+        );
+    }
+}
+
+/// Returns waypoints from the position to the destination, at the centers of tiles in between.
+fn find_waypoints(zone: &Zone, position: Vec2, destination: Vec2) -> Option<Vec<Vec2>> {
+    let start = zone.tile_at(position)?;
+    let goal = zone.tile_at(destination)?;
+    let tiles = zone.find_path(start, goal, CREATURE_TILE_SIZE)?;
+
+    let mut waypoints: Vec<Vec2> = tiles
+        .into_iter()
+        .map(|(x, y)| zone.tile_position(x, y))
+        .collect();
+    if let Some(waypoint) = waypoints.last_mut() {
+        *waypoint = destination;
+    } else {
+        waypoints.push(destination);
+    }
+
+    Some(waypoints)
+}
+
+fn move_to_system(
+    mut commands: Commands,
+    zone: Option<Res<Zone>>,
+    mut move_to_event_reader: EventReader<MoveTo>,
+    query: Query<&Transform, With<Creature>>,
+) {
+    let zone = match zone {
+        Some(result) => result,
+        None => return,
+    };
+
+    for move_to in move_to_event_reader.iter() {
+        let transform = match query.get(move_to.entity) {
+            Ok(result) => result,
+            Err(_) => continue,
+        };
+
+        match find_waypoints(&zone, transform.translation.truncate(), move_to.destination) {
+            Some(waypoints) => {
+                commands
+                    .entity(move_to.entity)
+                    .insert(Path::new(move_to.destination, waypoints));
+            }
+            None => {
+                info!("No path.");
+
+                commands.entity(move_to.entity).remove::<Path>();
+            }
+        }
+    }
+}
+
+fn follow_path_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    zone: Option<Res<Zone>>,
+    mut change_position_event_writer: EventWriter<ChangePosition>,
+    mut query: Query<(Entity, &Transform, &mut Path)>,
+) {
+    for (entity, transform, mut path) in query.iter_mut() {
+        let position = transform.translation.truncate();
+
+        while let Some(waypoint) = path.waypoints.first() {
+            if waypoint.distance(position) >= ARRIVAL_DISTANCE {
+                break;
+            }
+
+            path.waypoints.remove(0);
+            path.closest_distance = f32::INFINITY;
+        }
+
+        let waypoint = match path.waypoints.first() {
+            Some(result) => *result,
+            None => {
+                commands.entity(entity).remove::<Path>();
+
+                continue;
+            }
+        };
+
+        let distance = position.distance(waypoint);
+        if distance < path.closest_distance {
+            path.closest_distance = distance;
+            path.blocked_timer.reset();
+        } else {
+            path.blocked_timer.tick(time.delta());
+        }
+
+        if path.blocked_timer.finished() {
+            let waypoints = zone
+                .as_ref()
+                .and_then(|zone| find_waypoints(zone, position, path.destination));
+            match waypoints {
+                Some(waypoints) => {
+                    let destination = path.destination;
+                    *path = Path::new(destination, waypoints);
+                }
+                None => {
+                    info!("No path.");
+
+                    commands.entity(entity).remove::<Path>();
+                }
+            }
+
+            continue;
+        }
+
+        let step = CREATURE_SPEED * time.delta_seconds();
+        if step <= 0.0 {
+            continue;
+        }
+
+        change_position_event_writer.send(ChangePosition {
+            entity,
+            direction: (waypoint - position) / step.max(distance),
+        });
+    }
+}
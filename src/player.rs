@@ -1,15 +1,20 @@
 use crate::{
-    ability::{Ability, AbilityTargetMode, CancelCastAbility, CastAbility, TryAbility},
+    ability::{CancelCastAbility, CastAbility},
     creature::Creature,
-    effect::{Effect, LastingEffect, MomentaryEffect, MomentaryEffectSchedule},
-    position::ChangePosition,
+    cursor::WorldCursor,
+    enemy::Enemy,
+    health::Health,
+    input_map::{AnalogMovement, InputAction},
+    navigation::{MoveTo, Path},
+    position::{ChangePosition, Facing},
+    spellbook::{AbilityPlacement, TryActionBarSlot},
+    sprite::Sprite,
     target::Target,
-    AppState, CAMERA_SCALE, WINDOW_HEIGHT, WINDOW_WIDTH,
+    zone::Zone,
+    AppState,
 };
 use bevy::prelude::*;
 
-const DISTANCE_LIMIT: f32 = 40.0;
-
 /// Event to communicate player target changing.
 pub struct PlayerTargetChanged {
     pub target_entity: Option<Entity>,
@@ -24,184 +29,319 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<PlayerTargetChanged>().add_system_set(
             SystemSet::on_update(AppState::Game)
-                .with_system(handle_keyboard_input_system)
-                .with_system(handle_cursor_moved_system),
+                .with_system(handle_input_system)
+                .with_system(handle_move_input_system)
+                .with_system(handle_placement_input_system)
+                .with_system(handle_target_input_system),
         );
     }
 }
 
-fn handle_keyboard_input_system(
-    keyboard_input: Res<Input<KeyCode>>,
+#[allow(clippy::type_complexity)]
+fn handle_input_system(
+    mut commands: Commands,
+    action_input: Res<Input<InputAction>>,
+    analog_movement: Res<AnalogMovement>,
     mut change_position_event_writer: EventWriter<ChangePosition>,
-    mut try_ability_event_writer: EventWriter<TryAbility>,
+    mut try_action_bar_slot_event_writer: EventWriter<TryActionBarSlot>,
     mut cancel_cast_ability_event_writer: EventWriter<CancelCastAbility>,
-    query: Query<(Entity, &Target, Option<&CastAbility>), With<Player>>,
+    query: Query<(Entity, Option<&CastAbility>, Option<&Path>), With<Player>>,
 ) {
-    let (entity, target, cast_ability) = query.single();
+    let (entity, cast_ability, path) = query.single();
 
     let mut direction = Vec2::ZERO;
 
-    if keyboard_input.pressed(KeyCode::A) {
+    if action_input.pressed(InputAction::MoveLeft) {
         direction -= Vec2::X;
     }
 
-    if keyboard_input.pressed(KeyCode::D) {
+    if action_input.pressed(InputAction::MoveRight) {
         direction += Vec2::X;
     }
 
-    if keyboard_input.pressed(KeyCode::S) {
+    if action_input.pressed(InputAction::MoveDown) {
         direction -= Vec2::Y;
     }
 
-    if keyboard_input.pressed(KeyCode::W) {
+    if action_input.pressed(InputAction::MoveUp) {
         direction += Vec2::Y;
     }
 
+    if direction == Vec2::ZERO {
+        direction = analog_movement.direction;
+    }
+
     if direction != Vec2::ZERO {
+        // Moving directly cancels moving to a destination.
+        if path.is_some() {
+            commands.entity(entity).remove::<Path>();
+        }
+
         change_position_event_writer.send(ChangePosition { entity, direction });
     }
 
-    if keyboard_input.just_pressed(KeyCode::Key1) {
-        try_ability_event_writer.send(TryAbility {
-            source: entity,
-            ability: Ability {
-                id: 0,
-                name: "Fireball",
-                mana_points: 20,
-                cast_duration: 2.5,
-                cooldown_duration: 0.0,
-                range: 200.0,
-                effect: (
-                    Effect::Momentary(
-                        MomentaryEffect::Damage(30, 50),
-                        MomentaryEffectSchedule::Once,
-                    ),
-                    AbilityTargetMode::Single,
-                ),
-                secondary_effect: Some((
-                    Effect::Momentary(
-                        MomentaryEffect::Damage(2, 3),
-                        MomentaryEffectSchedule::Periodic(3.0, 12.0),
-                    ),
-                    AbilityTargetMode::Single,
-                )),
-            },
-            target: target.entity,
-        });
+    for (slot, action) in InputAction::CAST_SLOTS.into_iter().enumerate() {
+        if action_input.just_pressed(action) {
+            try_action_bar_slot_event_writer.send(TryActionBarSlot {
+                source: entity,
+                slot,
+                target_position: None,
+            });
+        }
     }
 
-    if keyboard_input.just_pressed(KeyCode::Key2) {
-        try_ability_event_writer.send(TryAbility {
-            source: entity,
-            ability: Ability {
-                id: 1,
-                name: "Blaze",
-                mana_points: 30,
-                cast_duration: 0.0,
-                cooldown_duration: 10.0,
-                range: 80.0,
-                effect: (
-                    Effect::Momentary(
-                        MomentaryEffect::Damage(20, 30),
-                        MomentaryEffectSchedule::Once,
-                    ),
-                    AbilityTargetMode::Area,
-                ),
-                secondary_effect: None,
-            },
-            target: target.entity,
-        });
+    if cast_ability.is_some() && action_input.just_pressed(InputAction::CancelCast) {
+        cancel_cast_ability_event_writer.send(CancelCastAbility { source: entity })
     }
+}
 
-    if keyboard_input.just_pressed(KeyCode::Key3) {
-        try_ability_event_writer.send(TryAbility {
-            source: entity,
-            ability: Ability {
-                id: 2,
-                name: "Lesser Heal",
-                mana_points: 15,
-                cast_duration: 1.5,
-                cooldown_duration: 0.0,
-                range: 200.0,
-                effect: (
-                    Effect::Momentary(MomentaryEffect::Heal(40, 60), MomentaryEffectSchedule::Once),
-                    AbilityTargetMode::Single,
-                ),
-                secondary_effect: None,
-            },
-            target: target.entity,
-        });
+fn handle_move_input_system(
+    action_input: Res<Input<InputAction>>,
+    world_cursor: Res<WorldCursor>,
+    zone: Res<Zone>,
+    mut move_to_event_writer: EventWriter<MoveTo>,
+    query: Query<Entity, With<Player>>,
+) {
+    if !action_input.just_pressed(InputAction::MoveToCursor) {
+        return;
     }
 
-    if keyboard_input.just_pressed(KeyCode::Q) {
-        try_ability_event_writer.send(TryAbility {
-            source: entity,
-            ability: Ability {
-                id: 3,
-                name: "Silence",
-                mana_points: 20,
-                cast_duration: 0.0,
-                cooldown_duration: 45.0,
-                range: 200.0,
-                effect: (
-                    Effect::Lasting(LastingEffect::Silence, 4.0),
-                    AbilityTargetMode::Single,
-                ),
-                secondary_effect: None,
-            },
-            target: target.entity,
-        });
+    let tile = match world_cursor
+        .position
+        .and_then(|cursor_position| zone.tile_at(cursor_position))
+    {
+        Some(result) => result,
+        None => return,
+    };
+
+    if zone.is_obstructed(tile.0, tile.1) {
+        info!("Destination obstructed.");
+
+        return;
     }
 
-    if cast_ability.is_some() && keyboard_input.just_pressed(KeyCode::Escape) {
-        cancel_cast_ability_event_writer.send(CancelCastAbility { source: entity })
+    move_to_event_writer.send(MoveTo {
+        entity: query.single(),
+        destination: zone.tile_position(tile.0, tile.1),
+    });
+}
+
+fn handle_placement_input_system(
+    mut commands: Commands,
+    action_input: Res<Input<InputAction>>,
+    world_cursor: Res<WorldCursor>,
+    mut try_action_bar_slot_event_writer: EventWriter<TryActionBarSlot>,
+    query: Query<(Entity, &AbilityPlacement), With<Player>>,
+) {
+    let (entity, ability_placement) = match query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    if action_input.just_pressed(InputAction::SelectTarget) {
+        let cursor_position = match world_cursor.position {
+            Some(result) => result,
+            None => return,
+        };
+
+        try_action_bar_slot_event_writer.send(TryActionBarSlot {
+            source: entity,
+            slot: ability_placement.slot,
+            target_position: Some(cursor_position),
+        });
+        commands.entity(entity).remove::<AbilityPlacement>();
+    } else if action_input.just_pressed(InputAction::CancelCast) {
+        commands.entity(entity).remove::<AbilityPlacement>();
     }
 }
 
-fn handle_cursor_moved_system(
-    mut cursor_moved_event_reader: EventReader<CursorMoved>,
+#[allow(clippy::type_complexity)]
+fn handle_target_input_system(
+    action_input: Res<Input<InputAction>>,
+    world_cursor: Res<WorldCursor>,
     mut player_target_changed_event_writer: EventWriter<PlayerTargetChanged>,
     creature_query: Query<(Entity, &Transform), With<Creature>>,
-    mut player_query: Query<&mut Target, With<Player>>,
+    hostile_query: Query<(Entity, &Transform, &Health), With<Enemy>>,
+    mut player_query: Query<
+        (
+            &Transform,
+            &Facing,
+            &mut Target,
+            Option<&CastAbility>,
+            Option<&AbilityPlacement>,
+        ),
+        With<Player>,
+    >,
 ) {
-    let cursor_moved = match cursor_moved_event_reader.iter().last() {
-        Some(result) => result,
-        None => return,
+    let (transform, facing, mut player_target, cast_ability, ability_placement) =
+        player_query.single_mut();
+
+    // Clicks and Escape go to placing an ability instead.
+    if ability_placement.is_some() {
+        return;
+    }
+
+    let target_entity = if action_input.just_pressed(InputAction::SelectTarget) {
+        let cursor_position = match world_cursor.position {
+            Some(result) => result,
+            None => return,
+        };
+
+        // Clicking elsewhere keeps the current target.
+        match creature_at(cursor_position, &creature_query) {
+            Some(result) => Some(result),
+            None => return,
+        }
+    } else if action_input.any_just_pressed([InputAction::TargetNext, InputAction::TargetPrevious])
+    {
+        let entities = hostile_entities_in_front(
+            transform.translation.truncate(),
+            facing.direction,
+            &hostile_query,
+        );
+
+        cycle_entity(
+            &entities,
+            player_target.entity,
+            action_input.just_pressed(InputAction::TargetNext),
+        )
+    } else if action_input.just_pressed(InputAction::ClearTarget) && cast_ability.is_none() {
+        None
+    } else {
+        return;
     };
 
-    let cursor_position = cursor_moved.position;
-    let cursor_position_matrix = cursor_position.extend(0.0).extend(1.0);
+    if player_target.entity != target_entity {
+        player_target.entity = target_entity;
 
-    // TODO: Calcuate based on camera's actual transform, in case it's transformed down the line.
-    let camera_transform = Transform::default()
-        .with_translation(Vec3::new(WINDOW_WIDTH, WINDOW_HEIGHT, 0.0) / -2.0 * CAMERA_SCALE)
-        .with_scale(Vec2::splat(CAMERA_SCALE).extend(1.0));
-    let adjusted_cursor_position_matrix =
-        camera_transform.compute_matrix() * cursor_position_matrix;
-    let adjusted_cursor_position = adjusted_cursor_position_matrix.truncate().truncate();
+        player_target_changed_event_writer.send(PlayerTargetChanged {
+            target_entity: player_target.entity,
+        });
+    }
+}
 
-    let closest_creature_entity = creature_query
+/// Returns the creature whose sprite contains the position, closest to its center if sprites overlap.
+fn creature_at(
+    position: Vec2,
+    creature_query: &Query<(Entity, &Transform), With<Creature>>,
+) -> Option<Entity> {
+    creature_query
         .iter()
-        .fold(None, |closest_entity, (entity, transform)| {
-            let position = transform.translation.truncate();
-            let distance = position.distance(adjusted_cursor_position);
-
-            match closest_entity {
-                None if distance < DISTANCE_LIMIT => Some((entity, distance)),
-                Some((_, previous_distance)) if distance < previous_distance => {
-                    Some((entity, distance))
-                }
-                _ => closest_entity,
+        .filter_map(|(entity, transform)| {
+            let offset = position - transform.translation.truncate();
+            if offset.abs().max_element() > Sprite::SIZE / 2.0 {
+                return None;
             }
+
+            Some((entity, offset.length()))
         })
-        .map(|(entity, _)| entity);
+        .min_by(|(_, distance), (_, other_distance)| distance.total_cmp(other_distance))
+        .map(|(entity, _)| entity)
+}
 
-    let mut player_target = player_query.single_mut();
-    if player_target.entity != closest_creature_entity {
-        player_target.entity = closest_creature_entity;
+/// Returns living hostile creatures in front of the position, ordered from nearest to farthest.
+fn hostile_entities_in_front(
+    position: Vec2,
+    facing_direction: Vec2,
+    hostile_query: &Query<(Entity, &Transform, &Health), With<Enemy>>,
+) -> Vec<Entity> {
+    let mut hostiles: Vec<(Entity, f32)> = hostile_query
+        .iter()
+        .filter(|(_, _, health)| health.points > 0)
+        .filter_map(|(entity, hostile_transform, _)| {
+            let offset = hostile_transform.translation.truncate() - position;
+            if offset.dot(facing_direction) < 0.0 {
+                return None;
+            }
 
-        player_target_changed_event_writer.send(PlayerTargetChanged {
-            target_entity: player_target.entity,
-        });
+            Some((entity, offset.length()))
+        })
+        .collect();
+    hostiles.sort_by(|(_, distance), (_, other_distance)| distance.total_cmp(other_distance));
+
+    hostiles.into_iter().map(|(entity, _)| entity).collect()
+}
+
+/// Returns the entity after, or before, the current one, wrapping around. Starts from either end without a current one.
+fn cycle_entity(entities: &[Entity], current: Option<Entity>, is_forward: bool) -> Option<Entity> {
+    if entities.is_empty() {
+        return None;
     }
+
+    let count = entities.len();
+    let index =
+        match current.and_then(|current| entities.iter().position(|entity| *entity == current)) {
+            Some(index) if is_forward => (index + 1) % count,
+            Some(index) => (index + count - 1) % count,
+            None if is_forward => 0,
+            None => count - 1,
+        };
+
+    Some(entities[index])
+}
+
+#[test]
+fn test_gamepad_input() {
+    use crate::input_map::{self, InputMap};
+    use bevy::input::{
+        gamepad::{GamepadEventRaw, GamepadEventType, GamepadInfo},
+        InputPlugin, InputSystem,
+    };
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .init_resource::<InputMap>()
+        .init_resource::<Input<InputAction>>()
+        .init_resource::<AnalogMovement>()
+        .add_event::<ChangePosition>()
+        .add_event::<TryActionBarSlot>()
+        .add_event::<CancelCastAbility>()
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            input_map::update_system.after(InputSystem),
+        )
+        .add_system(handle_input_system);
+
+    let entity = app.world.spawn(Player).id();
+
+    let gamepad = Gamepad::new(0);
+    let mut gamepad_events = app.world.resource_mut::<Events<GamepadEventRaw>>();
+    gamepad_events.send(GamepadEventRaw::new(
+        gamepad,
+        GamepadEventType::Connected(GamepadInfo {
+            name: "Test".to_string(),
+        }),
+    ));
+    gamepad_events.send(GamepadEventRaw::new(
+        gamepad,
+        GamepadEventType::AxisChanged(GamepadAxisType::LeftStickX, 0.6),
+    ));
+    gamepad_events.send(GamepadEventRaw::new(
+        gamepad,
+        GamepadEventType::ButtonChanged(GamepadButtonType::South, 1.0),
+    ));
+
+    // The gamepad is registered after input actions are updated on the first frame.
+    app.update();
+    app.update();
+
+    let change_position_events = app.world.resource::<Events<ChangePosition>>();
+    let mut change_position_event_reader = change_position_events.get_reader();
+    let change_position = change_position_event_reader
+        .iter(change_position_events)
+        .last()
+        .unwrap();
+    assert_eq!(change_position.entity, entity);
+    assert!(change_position
+        .direction
+        .abs_diff_eq(Vec2::new(0.5, 0.0), 0.001));
+
+    let try_action_bar_slot_events = app.world.resource::<Events<TryActionBarSlot>>();
+    let slots: Vec<usize> = try_action_bar_slot_events
+        .get_reader()
+        .iter(try_action_bar_slot_events)
+        .map(|try_action_bar_slot| try_action_bar_slot.slot)
+        .collect();
+    assert_eq!(slots, vec![0]);
 }
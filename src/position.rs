@@ -15,6 +15,18 @@ pub struct ChangePosition {
 #[derive(Component)]
 pub struct ChangingPosition;
 
+/// Component to store the direction a creature last tried to move towards.
+#[derive(Component)]
+pub struct Facing {
+    pub direction: Vec2,
+}
+
+impl Default for Facing {
+    fn default() -> Self {
+        Self { direction: Vec2::X }
+    }
+}
+
 pub struct PositionPlugin;
 
 impl Plugin for PositionPlugin {
@@ -29,13 +41,24 @@ fn change_position_system(
     mut commands: Commands,
     time: Res<Time>,
     mut change_position_event_reader: EventReader<ChangePosition>,
-    mut query: Query<(Entity, &mut Transform, Option<&ChangingPosition>), With<Creature>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut Facing,
+            Option<&ChangingPosition>,
+        ),
+        With<Creature>,
+    >,
     mut changing_position_query: Query<Entity, (With<ChangingPosition>, With<Creature>)>,
     obstacle_query: Query<&Transform, (With<Obstacle>, Without<Creature>)>,
 ) {
     let mut entities_changing_position = vec![];
     for change_position in change_position_event_reader.iter() {
-        let (entity, mut transform, moving) = query.get_mut(change_position.entity).unwrap();
+        let (entity, mut transform, mut facing, moving) =
+            query.get_mut(change_position.entity).unwrap();
+
+        facing.direction = change_position.direction.normalize();
 
         let is_colliding = |translation| {
             obstacle_query.iter().any(|obstacle_transform| {
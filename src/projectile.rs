@@ -0,0 +1,198 @@
+use crate::{
+    ability::{send_perform_effects, Ability},
+    creature::Creature,
+    effect::PerformEffect,
+    health::Health,
+    intersect_line_aabb::is_intersecting,
+    level::Obstacle,
+    zone::Tile,
+    AppState,
+};
+use bevy::prelude::*;
+
+/// Component to carry an ability's effects to its target, performed on impact.
+#[derive(Component)]
+pub struct Projectile {
+    source: Entity,
+    target: Entity,
+    ability: Ability,
+}
+
+impl Projectile {
+    /// Depth above creatures.
+    pub const TRANSLATION_Z: f32 = 2.0;
+
+    pub fn new(source: Entity, target: Entity, ability: Ability) -> Self {
+        Self {
+            source,
+            target,
+            ability,
+        }
+    }
+}
+
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_update(AppState::Game).with_system(travel_system))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_system));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn travel_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut perform_effect_event_writer: EventWriter<PerformEffect>,
+    mut query: Query<(Entity, &mut Transform, &Projectile), (Without<Creature>, Without<Obstacle>)>,
+    target_query: Query<(&Transform, &Health), With<Creature>>,
+    creature_query: Query<(Entity, &Transform), With<Creature>>,
+    obstacle_query: Query<&Transform, With<Obstacle>>,
+) {
+    for (entity, mut transform, projectile) in query.iter_mut() {
+        let target_position = match target_query.get(projectile.target) {
+            Ok((target_transform, target_health)) if target_health.points > 0 => {
+                target_transform.translation.truncate()
+            }
+            _ => {
+                info!("Projectile fizzled, target is gone.");
+                commands.entity(entity).despawn();
+
+                continue;
+            }
+        };
+
+        let position = transform.translation.truncate();
+        let offset = target_position - position;
+        let step = projectile.ability.projectile.unwrap().speed * time.delta_seconds();
+        let next_position = if offset.length() <= step {
+            target_position
+        } else {
+            position + offset.normalize() * step
+        };
+
+        if obstacle_query.iter().any(|obstacle_transform| {
+            is_intersecting(
+                position,
+                next_position,
+                obstacle_transform.translation.truncate(),
+                Vec2::splat(Tile::SIZE),
+            )
+        }) {
+            info!("Projectile fizzled, blocked by an obstacle.");
+            commands.entity(entity).despawn();
+
+            continue;
+        }
+
+        if next_position == target_position {
+            send_perform_effects(
+                projectile.source,
+                &projectile.ability,
+                Some(projectile.target),
+                None,
+                target_position,
+                offset.normalize_or_zero(),
+                &mut perform_effect_event_writer,
+                &creature_query,
+                &obstacle_query,
+            );
+            commands.entity(entity).despawn();
+
+            continue;
+        }
+
+        transform.translation.x = next_position.x;
+        transform.translation.y = next_position.y;
+    }
+}
+
+fn despawn_system(mut commands: Commands, query: Query<Entity, With<Projectile>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[test]
+fn test_projectile() {
+    use crate::{
+        ability::{AbilityProjectile, AbilityTargetMode},
+        effect::{Effect, MomentaryEffect, MomentaryEffectSchedule},
+        sprite::Sprite,
+    };
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<PerformEffect>()
+        .add_system(travel_system);
+
+    let source = app.world.spawn(Transform::default()).id();
+    let target = app
+        .world
+        .spawn((
+            Transform::from_xyz(100.0, 0.0, 0.0),
+            Health::new(100),
+            Creature,
+        ))
+        .id();
+
+    // Returns the number of effects performed and whether the projectile is gone.
+    let launch = |app: &mut App| {
+        app.world.resource_mut::<Events<PerformEffect>>().clear();
+        let projectile = Projectile::new(
+            source,
+            target,
+            Ability {
+                id: 0,
+                name: "Fireball".to_string(),
+                mana_points: 0,
+                cast_duration: 0.0,
+                cooldown_duration: None,
+                range: 200.0,
+                effects: vec![(
+                    Effect::Momentary(MomentaryEffect::Damage(1, 1), MomentaryEffectSchedule::Once),
+                    AbilityTargetMode::Single,
+                )],
+                projectile: Some(AbilityProjectile {
+                    speed: 1_000_000_000.0,
+                    sprite: Sprite::Fireball,
+                }),
+            },
+        );
+        let entity = app.world.spawn((Transform::default(), projectile)).id();
+
+        // Time doesn't advance on the first update.
+        app.update();
+        let performed_count_before_impact = app.world.resource::<Events<PerformEffect>>().len();
+        app.update();
+
+        let perform_effect_events = app.world.resource::<Events<PerformEffect>>();
+        let mut perform_effect_event_reader = perform_effect_events.get_reader();
+        let performed_targets = perform_effect_event_reader
+            .iter(perform_effect_events)
+            .map(|perform_effect| perform_effect.target)
+            .collect::<Vec<_>>();
+
+        (
+            performed_count_before_impact,
+            performed_targets,
+            app.world.get_entity(entity).is_none(),
+        )
+    };
+
+    // Effects are performed on impact only.
+    assert_eq!(launch(&mut app), (0, vec![target], true));
+
+    // Fizzles when blocked by an obstacle.
+    let obstacle = app
+        .world
+        .spawn((Transform::from_xyz(50.0, 0.0, 0.0), Obstacle))
+        .id();
+    assert_eq!(launch(&mut app), (0, vec![], true));
+    app.world.despawn(obstacle);
+
+    // Fizzles when the target dies.
+    app.world.get_mut::<Health>(target).unwrap().points = 0;
+    assert_eq!(launch(&mut app), (0, vec![], true));
+}
@@ -0,0 +1,157 @@
+use crate::{
+    ability::{Ability, TryAbility},
+    ability_registry::AbilityRegistry,
+    target::Target,
+    AppState,
+};
+use bevy::prelude::*;
+
+/// Event to assign an ability to an action bar slot, or to clear the slot.
+pub struct SetActionBarSlot {
+    pub entity: Entity,
+    pub slot: usize,
+    pub ability_id: Option<u8>,
+}
+
+/// Event to try the ability in an action bar slot on the current target. Abilities placed on the ground start
+/// placement without a target position.
+pub struct TryActionBarSlot {
+    pub source: Entity,
+    pub slot: usize,
+    pub target_position: Option<Vec2>,
+}
+
+/// Component to indicate an ability waiting for a target position to be placed at.
+#[derive(Component)]
+pub struct AbilityPlacement {
+    pub slot: usize,
+    pub ability: Ability,
+}
+
+/// Component to store abilities known by a creature.
+#[derive(Component, Default, Clone)]
+pub struct Spellbook {
+    pub ability_ids: Vec<u8>,
+}
+
+impl Spellbook {
+    pub fn new(ability_ids: &[u8]) -> Self {
+        Self {
+            ability_ids: ability_ids.to_vec(),
+        }
+    }
+
+    pub fn contains(&self, ability_id: u8) -> bool {
+        self.ability_ids.contains(&ability_id)
+    }
+}
+
+/// Component to map action bar slots to abilities.
+#[derive(Component, Default, Clone)]
+pub struct ActionBar {
+    ability_ids: [Option<u8>; ActionBar::SLOT_COUNT],
+}
+
+impl ActionBar {
+    pub const SLOT_COUNT: usize = 8;
+
+    /// Fills slots in order with the spellbook's abilities.
+    pub fn from_spellbook(spellbook: &Spellbook) -> Self {
+        let mut action_bar = Self::default();
+        for (slot, ability_id) in spellbook
+            .ability_ids
+            .iter()
+            .take(Self::SLOT_COUNT)
+            .enumerate()
+        {
+            action_bar.ability_ids[slot] = Some(*ability_id);
+        }
+
+        action_bar
+    }
+
+    pub fn ability_id(&self, slot: usize) -> Option<u8> {
+        self.ability_ids.get(slot).copied().flatten()
+    }
+}
+
+pub struct SpellbookPlugin;
+
+impl Plugin for SpellbookPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SetActionBarSlot>()
+            .add_event::<TryActionBarSlot>()
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(set_action_bar_slot_system)
+                    .with_system(try_action_bar_slot_system),
+            );
+    }
+}
+
+fn set_action_bar_slot_system(
+    mut set_action_bar_slot_event_reader: EventReader<SetActionBarSlot>,
+    mut query: Query<(&Spellbook, &mut ActionBar)>,
+) {
+    for set_action_bar_slot in set_action_bar_slot_event_reader.iter() {
+        let (spellbook, mut action_bar) = match query.get_mut(set_action_bar_slot.entity) {
+            Ok(result) => result,
+            Err(_) => continue,
+        };
+
+        if set_action_bar_slot.slot >= ActionBar::SLOT_COUNT {
+            continue;
+        }
+
+        if let Some(ability_id) = set_action_bar_slot.ability_id {
+            if !spellbook.contains(ability_id) {
+                info!("Ability ({ability_id}) not in spellbook.");
+
+                continue;
+            }
+        }
+
+        action_bar.ability_ids[set_action_bar_slot.slot] = set_action_bar_slot.ability_id;
+    }
+}
+
+fn try_action_bar_slot_system(
+    mut commands: Commands,
+    ability_registry: Res<AbilityRegistry>,
+    mut try_action_bar_slot_event_reader: EventReader<TryActionBarSlot>,
+    mut try_ability_event_writer: EventWriter<TryAbility>,
+    query: Query<(&ActionBar, &Target)>,
+) {
+    for try_action_bar_slot in try_action_bar_slot_event_reader.iter() {
+        let (action_bar, target) = match query.get(try_action_bar_slot.source) {
+            Ok(result) => result,
+            Err(_) => continue,
+        };
+
+        let ability = match action_bar
+            .ability_id(try_action_bar_slot.slot)
+            .and_then(|ability_id| ability_registry.get(ability_id))
+        {
+            Some(result) => result,
+            None => continue,
+        };
+
+        if ability.requires_target_position() && try_action_bar_slot.target_position.is_none() {
+            commands
+                .entity(try_action_bar_slot.source)
+                .insert(AbilityPlacement {
+                    slot: try_action_bar_slot.slot,
+                    ability: ability.clone(),
+                });
+
+            continue;
+        }
+
+        try_ability_event_writer.send(TryAbility {
+            source: try_action_bar_slot.source,
+            ability: ability.clone(),
+            target: target.entity,
+            target_position: try_action_bar_slot.target_position,
+        });
+    }
+}
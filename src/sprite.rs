@@ -1,9 +1,12 @@
-#[derive(Debug, Clone, Copy)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
 pub enum Sprite {
     Player = 0,
     Goblin = 1,
     TargetIndicator = 2,
     Wall = 3,
+    Fireball = 4,
 }
 
 impl Sprite {
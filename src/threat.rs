@@ -0,0 +1,171 @@
+use crate::{
+    effect::{LastingEffect, LastingEffects, MomentaryEffectPerformed, PerformedMomentaryEffect},
+    health::Health,
+    AppState,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+const DAMAGE_THREAT_MULTIPLIER: f32 = 1.0;
+/// Threat from healing, split between all creatures engaged with the healed one.
+const HEAL_THREAT_MULTIPLIER: f32 = 0.5;
+/// Fraction of threat kept after each second.
+const THREAT_DECAY_PER_SECOND: f32 = 0.95;
+
+/// Component to store how much each creature has threatened this one, to choose who to attack.
+#[derive(Component, Default)]
+pub struct ThreatTable {
+    threat_by_entity: HashMap<Entity, f32>,
+}
+
+impl ThreatTable {
+    /// Returns entities with their threat, ordered from highest to lowest.
+    pub fn ranked(&self) -> Vec<(Entity, f32)> {
+        let mut ranked: Vec<(Entity, f32)> = self
+            .threat_by_entity
+            .iter()
+            .map(|(entity, threat)| (*entity, *threat))
+            .collect();
+        ranked.sort_by(|(_, threat), (_, other_threat)| other_threat.total_cmp(threat));
+
+        ranked
+    }
+
+    pub fn is_engaged(&self, entity: Entity) -> bool {
+        self.threat_by_entity.contains_key(&entity)
+    }
+
+    /// Starts tracking the entity without adding threat.
+    pub fn engage(&mut self, entity: Entity) {
+        self.threat_by_entity.entry(entity).or_insert(0.0);
+    }
+
+    fn add(&mut self, entity: Entity, threat: f32) {
+        *self.threat_by_entity.entry(entity).or_insert(0.0) += threat;
+    }
+}
+
+/// Returns the source of an ongoing taunt, which overrides threat.
+pub fn taunting_entity(lasting_effects: &LastingEffects) -> Option<Entity> {
+    lasting_effects
+        .instances
+        .iter()
+        .rev()
+        .find(|instance| matches!(instance.effect, LastingEffect::Taunt))
+        .map(|instance| instance.source)
+}
+
+pub struct ThreatPlugin;
+
+impl Plugin for ThreatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(AppState::Game)
+                .with_system(add_threat_system)
+                .with_system(decay_threat_system),
+        );
+    }
+}
+
+fn add_threat_system(
+    mut momentary_effect_performed_event_reader: EventReader<MomentaryEffectPerformed>,
+    mut query: Query<(Entity, &mut ThreatTable)>,
+) {
+    for momentary_effect_performed in momentary_effect_performed_event_reader.iter() {
+        let source = momentary_effect_performed.source;
+
+        match momentary_effect_performed.performed_momentary_effect {
+            PerformedMomentaryEffect::Damage(points, _) => {
+                let mut threat_table = match query.get_mut(momentary_effect_performed.entity) {
+                    Ok((_, result)) => result,
+                    Err(_) => continue,
+                };
+
+                threat_table.add(source, points as f32 * DAMAGE_THREAT_MULTIPLIER);
+            }
+            PerformedMomentaryEffect::Heal(points, _) => {
+                let healed_entity = momentary_effect_performed.entity;
+                let engaged_count = query
+                    .iter()
+                    .filter(|(entity, threat_table)| {
+                        *entity != source && threat_table.is_engaged(healed_entity)
+                    })
+                    .count();
+                if engaged_count == 0 {
+                    continue;
+                }
+
+                let threat = points as f32 * HEAL_THREAT_MULTIPLIER / engaged_count as f32;
+                for (entity, mut threat_table) in query.iter_mut() {
+                    if entity != source && threat_table.is_engaged(healed_entity) {
+                        threat_table.add(source, threat);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn decay_threat_system(
+    time: Res<Time>,
+    mut query: Query<&mut ThreatTable>,
+    health_query: Query<&Health>,
+) {
+    let decay = THREAT_DECAY_PER_SECOND.powf(time.delta_seconds());
+
+    for mut threat_table in query.iter_mut() {
+        threat_table.threat_by_entity.retain(|entity, threat| {
+            *threat *= decay;
+
+            matches!(health_query.get(*entity), Ok(health) if health.points > 0)
+        });
+    }
+}
+
+#[test]
+fn test_threat_table() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<MomentaryEffectPerformed>()
+        .add_system(add_threat_system);
+
+    let tank_entity = app.world.spawn(Health::new(100)).id();
+    let healer_entity = app.world.spawn(Health::new(100)).id();
+    let entity = app.world.spawn(ThreatTable::default()).id();
+    let other_entity = app.world.spawn(ThreatTable::default()).id();
+    let unengaged_entity = app.world.spawn(ThreatTable::default()).id();
+
+    let mut momentary_effect_performed_events =
+        app.world.resource_mut::<Events<MomentaryEffectPerformed>>();
+    momentary_effect_performed_events.send(MomentaryEffectPerformed {
+        source: tank_entity,
+        entity,
+        performed_momentary_effect: PerformedMomentaryEffect::Damage(30, false),
+    });
+    momentary_effect_performed_events.send(MomentaryEffectPerformed {
+        source: tank_entity,
+        entity: other_entity,
+        performed_momentary_effect: PerformedMomentaryEffect::Damage(10, false),
+    });
+    momentary_effect_performed_events.send(MomentaryEffectPerformed {
+        source: healer_entity,
+        entity: tank_entity,
+        performed_momentary_effect: PerformedMomentaryEffect::Heal(80, false),
+    });
+    app.update();
+
+    let threat_table = app.world.get::<ThreatTable>(entity).unwrap();
+    assert_eq!(
+        threat_table.ranked(),
+        vec![(tank_entity, 30.0), (healer_entity, 20.0)]
+    );
+
+    let other_threat_table = app.world.get::<ThreatTable>(other_entity).unwrap();
+    assert_eq!(
+        other_threat_table.ranked(),
+        vec![(healer_entity, 20.0), (tank_entity, 10.0)]
+    );
+
+    let unengaged_threat_table = app.world.get::<ThreatTable>(unengaged_entity).unwrap();
+    assert!(unengaged_threat_table.ranked().is_empty());
+}
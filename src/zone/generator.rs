@@ -0,0 +1,216 @@
+use super::{EnemySpawn, Tile, Zone};
+use crate::enemy::EnemyKind;
+use rand::{prelude::*, rngs::StdRng};
+use std::{fmt, ops::RangeInclusive};
+
+/// Attempts to place each room before giving up on it.
+const ROOM_PLACEMENT_ATTEMPTS: usize = 32;
+
+/// Settings to generate zones of rectangular rooms connected by corridors. The same seed always generates the same zone.
+pub struct ZoneGenerator {
+    pub columns: usize,
+    pub rows: usize,
+    pub room_count: usize,
+    /// Range of room widths and heights, excluding walls.
+    pub room_size: RangeInclusive<usize>,
+    pub enemy_kinds: Vec<EnemyKind>,
+    pub enemy_count: usize,
+}
+
+impl Default for ZoneGenerator {
+    fn default() -> Self {
+        Self {
+            columns: 40,
+            rows: 30,
+            room_count: 6,
+            room_size: 4..=8,
+            enemy_kinds: vec![EnemyKind::Goblin],
+            enemy_count: 4,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Room {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Room {
+    fn center(&self) -> (usize, usize) {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+
+    /// Returns whether the rooms overlap, or touch so that no wall would separate them.
+    fn is_adjacent(&self, other: &Room) -> bool {
+        self.x <= other.x + other.width
+            && other.x <= self.x + self.width
+            && self.y <= other.y + other.height
+            && other.y <= self.y + self.height
+    }
+}
+
+impl ZoneGenerator {
+    /// Generates a zone with the player in the first room and enemies spread over the others, verifying every enemy can be reached.
+    pub fn generate(&self, seed: u64) -> Result<Zone, ZoneGenerationError> {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut rooms: Vec<Room> = vec![];
+        for _ in 0..self.room_count {
+            for _ in 0..ROOM_PLACEMENT_ATTEMPTS {
+                let width = rng.gen_range(self.room_size.clone());
+                let height = rng.gen_range(self.room_size.clone());
+                if width + 2 > self.columns || height + 2 > self.rows {
+                    continue;
+                }
+
+                let room = Room {
+                    x: rng.gen_range(1..=self.columns - width - 1),
+                    y: rng.gen_range(1..=self.rows - height - 1),
+                    width,
+                    height,
+                };
+                if rooms.iter().all(|other| !room.is_adjacent(other)) {
+                    rooms.push(room);
+
+                    break;
+                }
+            }
+        }
+
+        if rooms.len() < 2 {
+            return Err(ZoneGenerationError::NotEnoughRooms);
+        }
+
+        let mut layer = vec![vec![Some(Tile::WALL); self.rows]; self.columns];
+        for room in &rooms {
+            for column_tiles in &mut layer[room.x..room.x + room.width] {
+                for tile in &mut column_tiles[room.y..room.y + room.height] {
+                    *tile = None;
+                }
+            }
+        }
+
+        for (room, previous_room) in rooms.iter().skip(1).zip(&rooms) {
+            let (x, y) = room.center();
+            let (previous_x, previous_y) = previous_room.center();
+            // Corridors bend once, going either horizontally or vertically first.
+            let corner = if rng.gen() {
+                (x, previous_y)
+            } else {
+                (previous_x, y)
+            };
+
+            for (from, to) in [((previous_x, previous_y), corner), (corner, (x, y))] {
+                for column_tiles in &mut layer[from.0.min(to.0)..=from.0.max(to.0)] {
+                    for tile in &mut column_tiles[from.1.min(to.1)..=from.1.max(to.1)] {
+                        *tile = None;
+                    }
+                }
+            }
+        }
+
+        let mut enemy_spawns = vec![];
+        let mut spawn_tiles = vec![rooms[0].center()];
+        for _ in 0..self.enemy_count {
+            let kind = match self.enemy_kinds.choose(&mut rng) {
+                Some(result) => *result,
+                None => break,
+            };
+
+            let room = rooms[1..].choose(&mut rng).unwrap();
+            let tile = (
+                rng.gen_range(room.x..room.x + room.width),
+                rng.gen_range(room.y..room.y + room.height),
+            );
+            if spawn_tiles.contains(&tile) {
+                continue;
+            }
+
+            spawn_tiles.push(tile);
+            enemy_spawns.push(EnemySpawn { kind, tile });
+        }
+
+        let zone = Zone {
+            columns: self.columns,
+            rows: self.rows,
+            layers: vec![layer],
+            player_spawn: rooms[0].center(),
+            enemy_spawns,
+        };
+
+        let reachable_tiles = zone.reachable_tiles(zone.player_spawn);
+        for enemy_spawn in &zone.enemy_spawns {
+            let (x, y) = enemy_spawn.tile;
+            if !reachable_tiles[x][y] {
+                return Err(ZoneGenerationError::UnreachableSpawn(x, y));
+            }
+        }
+
+        Ok(zone)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ZoneGenerationError {
+    NotEnoughRooms,
+    UnreachableSpawn(usize, usize),
+}
+
+impl fmt::Display for ZoneGenerationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotEnoughRooms => write!(formatter, "Zone has room for fewer than two rooms."),
+            Self::UnreachableSpawn(x, y) => write!(
+                formatter,
+                "Spawn ({x}, {y}) cannot be reached from the player spawn."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ZoneGenerationError {}
+
+#[test]
+fn test_zone_generator() {
+    let zone_generator = ZoneGenerator::default();
+
+    for seed in 0..100 {
+        let zone = match zone_generator.generate(seed) {
+            Ok(result) => result,
+            Err(error) => panic!("Seed ({seed}) failed: {error}"),
+        };
+
+        assert!(!zone.is_obstructed(zone.player_spawn.0, zone.player_spawn.1));
+        assert!(!zone.enemy_spawns.is_empty());
+    }
+
+    let zone = zone_generator.generate(7).unwrap();
+    let other_zone = zone_generator.generate(7).unwrap();
+    assert!(zone.layers == other_zone.layers);
+    assert_eq!(zone.player_spawn, other_zone.player_spawn);
+    assert_eq!(
+        zone.enemy_spawns
+            .iter()
+            .map(|enemy_spawn| enemy_spawn.tile)
+            .collect::<Vec<_>>(),
+        other_zone
+            .enemy_spawns
+            .iter()
+            .map(|enemy_spawn| enemy_spawn.tile)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        ZoneGenerator {
+            columns: 8,
+            rows: 8,
+            ..ZoneGenerator::default()
+        }
+        .generate(0)
+        .err(),
+        Some(ZoneGenerationError::NotEnoughRooms)
+    );
+}
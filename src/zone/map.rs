@@ -0,0 +1,200 @@
+use super::{EnemySpawn, Tile, Zone};
+use crate::enemy::EnemyKind;
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+use std::{collections::HashMap, fmt};
+
+/// Character for positions without a tile on a layer.
+const EMPTY_TILE: char = '.';
+
+/// Zone file format. Layers are written as rows of characters from top to bottom, each character mapped to a tile by the legend.
+#[derive(Deserialize)]
+struct ZoneMap {
+    legend: HashMap<char, Tile>,
+    layers: Vec<Vec<String>>,
+    player_spawn: (usize, usize),
+    #[serde(default)]
+    enemy_spawns: Vec<ZoneMapEnemySpawn>,
+}
+
+#[derive(Deserialize)]
+struct ZoneMapEnemySpawn {
+    kind: EnemyKind,
+    tile: (usize, usize),
+}
+
+impl ZoneMap {
+    fn into_zone(self) -> Result<Zone, ZoneMapError> {
+        let rows = self.layers.first().ok_or(ZoneMapError::NoLayers)?.len();
+        let columns = self.layers[0].first().map_or(0, |row| row.chars().count());
+        if columns == 0 {
+            return Err(ZoneMapError::NoLayers);
+        }
+
+        let mut layers = Vec::with_capacity(self.layers.len());
+        for (layer_index, layer_rows) in self.layers.iter().enumerate() {
+            if layer_rows.len() != rows {
+                return Err(ZoneMapError::LayerSizeMismatch(layer_index));
+            }
+
+            let mut layer = vec![vec![None; rows]; columns];
+            for (row_index, row) in layer_rows.iter().enumerate() {
+                if row.chars().count() != columns {
+                    return Err(ZoneMapError::LayerSizeMismatch(layer_index));
+                }
+
+                let y = rows - 1 - row_index;
+                for (x, character) in row.chars().enumerate() {
+                    if character == EMPTY_TILE {
+                        continue;
+                    }
+
+                    let tile = self
+                        .legend
+                        .get(&character)
+                        .ok_or(ZoneMapError::UnknownTile(character))?;
+                    layer[x][y] = Some(*tile);
+                }
+            }
+
+            layers.push(layer);
+        }
+
+        let zone = Zone {
+            columns,
+            rows,
+            layers,
+            player_spawn: self.player_spawn,
+            enemy_spawns: self
+                .enemy_spawns
+                .into_iter()
+                .map(|enemy_spawn| EnemySpawn {
+                    kind: enemy_spawn.kind,
+                    tile: enemy_spawn.tile,
+                })
+                .collect(),
+        };
+
+        for (x, y) in std::iter::once(zone.player_spawn)
+            .chain(zone.enemy_spawns.iter().map(|enemy_spawn| enemy_spawn.tile))
+        {
+            if zone.is_obstructed(x, y) {
+                return Err(ZoneMapError::ObstructedSpawn(x, y));
+            }
+        }
+
+        Ok(zone)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ZoneMapError {
+    NoLayers,
+    LayerSizeMismatch(usize),
+    UnknownTile(char),
+    ObstructedSpawn(usize, usize),
+}
+
+impl fmt::Display for ZoneMapError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoLayers => write!(formatter, "Zone has no tiles."),
+            Self::LayerSizeMismatch(layer_index) => write!(
+                formatter,
+                "Layer ({layer_index}) does not match the size of the first layer's first row."
+            ),
+            Self::UnknownTile(character) => {
+                write!(formatter, "Tile ({character}) is not in the legend.")
+            }
+            Self::ObstructedSpawn(x, y) => write!(
+                formatter,
+                "Spawn ({x}, {y}) is obstructed or outside the zone."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ZoneMapError {}
+
+fn zone_from_bytes(bytes: &[u8]) -> Result<Zone, bevy::asset::Error> {
+    let zone_map: ZoneMap = ron::de::from_bytes(bytes)?;
+
+    Ok(zone_map.into_zone()?)
+}
+
+#[derive(Default)]
+pub struct ZoneLoader;
+
+impl AssetLoader for ZoneLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let zone = zone_from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(zone));
+
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["zone.ron"]
+    }
+}
+
+#[test]
+fn test_zone_map_valid() {
+    let zone = zone_from_bytes(include_bytes!("../../assets/arena.zone.ron")).unwrap();
+
+    assert_eq!((zone.columns, zone.rows), (22, 16));
+    assert!(zone.is_obstructed(10, 6));
+    assert!(!zone.is_obstructed(zone.player_spawn.0, zone.player_spawn.1));
+    assert_eq!(zone.enemy_spawns.len(), 2);
+}
+
+#[test]
+fn test_zone_map_invalid() {
+    let zone_map = |layers: &str, player_spawn: &str| -> ZoneMap {
+        ron::de::from_str(&format!(
+            "(legend: {{ 'W': (sprite: Wall, is_obstructed: true) }}, layers: {layers}, player_spawn: {player_spawn})"
+        ))
+        .unwrap()
+    };
+
+    assert_eq!(
+        zone_map("[]", "(0, 0)").into_zone().err(),
+        Some(ZoneMapError::NoLayers)
+    );
+    assert_eq!(
+        zone_map(r#"[["WWW", "W.W", "WW"]]"#, "(1, 1)")
+            .into_zone()
+            .err(),
+        Some(ZoneMapError::LayerSizeMismatch(0))
+    );
+    assert_eq!(
+        zone_map(r#"[["WWW", "W?W", "WWW"]]"#, "(1, 1)")
+            .into_zone()
+            .err(),
+        Some(ZoneMapError::UnknownTile('?'))
+    );
+    assert_eq!(
+        zone_map(
+            r#"[["WWW", "W.W", "WWW"], ["...", ".W.", "..."]]"#,
+            "(1, 1)"
+        )
+        .into_zone()
+        .err(),
+        Some(ZoneMapError::ObstructedSpawn(1, 1))
+    );
+    assert_eq!(
+        zone_map(r#"[["WWW", "W.W", "WWW"]]"#, "(3, 1)")
+            .into_zone()
+            .err(),
+        Some(ZoneMapError::ObstructedSpawn(3, 1))
+    );
+}
@@ -0,0 +1,103 @@
+pub mod generator;
+mod map;
+mod pathfinding;
+
+use crate::{enemy::EnemyKind, sprite::Sprite};
+use bevy::{prelude::*, reflect::TypeUuid};
+use map::ZoneLoader;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Tile {
+    pub sprite: Sprite,
+    pub is_obstructed: bool,
+}
+
+impl Tile {
+    pub const SIZE: f32 = Sprite::SIZE;
+
+    const WALL: Self = Self {
+        sprite: Sprite::Wall,
+        is_obstructed: true,
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EnemySpawn {
+    pub kind: EnemyKind,
+    pub tile: (usize, usize),
+}
+
+/// Asset to store a zone's tile layers and spawn points, loaded from `.zone.ron` files. Inserted as a resource while a level is in progress.
+#[derive(Resource, TypeUuid, Clone)]
+#[uuid = "b3e9c1d4-6a52-4f0e-8c7d-2e4f9a1b5d36"]
+pub struct Zone {
+    columns: usize,
+    rows: usize,
+    /// Tiles indexed by layer, then column, then row from the bottom. Later layers are drawn on top.
+    pub layers: Vec<Vec<Vec<Option<Tile>>>>,
+    pub player_spawn: (usize, usize),
+    pub enemy_spawns: Vec<EnemySpawn>,
+}
+
+impl Zone {
+    pub fn tile_position(&self, x: usize, y: usize) -> Vec2 {
+        self.origin() + Vec2::new(x as f32 * Tile::SIZE, y as f32 * Tile::SIZE)
+    }
+
+    /// Returns whether a tile on any layer obstructs the position. Positions outside the zone are obstructed.
+    pub fn is_obstructed(&self, x: usize, y: usize) -> bool {
+        if x >= self.columns || y >= self.rows {
+            return true;
+        }
+
+        self.layers
+            .iter()
+            .any(|layer| matches!(layer[x][y], Some(tile) if tile.is_obstructed))
+    }
+
+    /// Returns which tiles can be walked to from the start, indexed by column then row.
+    pub fn reachable_tiles(&self, start: (usize, usize)) -> Vec<Vec<bool>> {
+        let mut reachable_tiles = vec![vec![false; self.rows]; self.columns];
+        let mut open_tiles = vec![start];
+        while let Some((x, y)) = open_tiles.pop() {
+            if self.is_obstructed(x, y) || reachable_tiles[x][y] {
+                continue;
+            }
+
+            reachable_tiles[x][y] = true;
+            open_tiles.extend([(x + 1, y), (x, y + 1)]);
+            if x > 0 {
+                open_tiles.push((x - 1, y));
+            }
+            if y > 0 {
+                open_tiles.push((x, y - 1));
+            }
+        }
+
+        reachable_tiles
+    }
+
+    pub fn width(&self) -> f32 {
+        self.columns as f32 * Tile::SIZE
+    }
+
+    pub fn height(&self) -> f32 {
+        self.rows as f32 * Tile::SIZE
+    }
+
+    pub fn origin(&self) -> Vec2 {
+        Vec2::new(
+            (self.width() - Tile::SIZE) / -2.0,
+            (self.height() - Tile::SIZE) / -2.0,
+        )
+    }
+}
+
+pub struct ZonePlugin;
+
+impl Plugin for ZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Zone>().init_asset_loader::<ZoneLoader>();
+    }
+}
@@ -0,0 +1,152 @@
+use super::Zone;
+use bevy::prelude::*;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+const ORTHOGONAL_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+impl Zone {
+    /// Returns the tile containing the position, if it is inside the zone.
+    pub fn tile_at(&self, position: Vec2) -> Option<(usize, usize)> {
+        let tile = ((position - self.origin()) / super::Tile::SIZE).round();
+        if tile.x < 0.0 || tile.y < 0.0 {
+            return None;
+        }
+
+        let (x, y) = (tile.x as usize, tile.y as usize);
+        if x >= self.columns || y >= self.rows {
+            return None;
+        }
+
+        Some((x, y))
+    }
+
+    /// Returns whether a creature spanning `size` tiles from the bottom left one fits on the tile.
+    fn is_walkable(&self, x: usize, y: usize, size: usize) -> bool {
+        (x..x + size).all(|x| (y..y + size).all(|y| !self.is_obstructed(x, y)))
+    }
+
+    /// Finds the shortest path between tiles with A*, for a creature spanning `size` tiles. Diagonal steps may not cut corners.
+    /// Returns tiles to go through after the start, ending with the goal.
+    pub fn find_path(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        size: usize,
+    ) -> Option<Vec<(usize, usize)>> {
+        if !self.is_walkable(goal.0, goal.1, size) {
+            return None;
+        }
+
+        let heuristic = |(x, y): (usize, usize)| {
+            let dx = x.abs_diff(goal.0) as u32;
+            let dy = y.abs_diff(goal.1) as u32;
+
+            ORTHOGONAL_COST * dx.max(dy) + (DIAGONAL_COST - ORTHOGONAL_COST) * dx.min(dy)
+        };
+
+        let mut open_tiles = BinaryHeap::from([Reverse((heuristic(start), start))]);
+        let mut cost_by_tile = HashMap::from([(start, 0)]);
+        let mut previous_by_tile: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+
+        while let Some(Reverse((_, tile))) = open_tiles.pop() {
+            if tile == goal {
+                let mut path = vec![goal];
+                while let Some(previous) = previous_by_tile.get(path.last().unwrap()) {
+                    if *previous == start {
+                        break;
+                    }
+
+                    path.push(*previous);
+                }
+                path.reverse();
+
+                return Some(path);
+            }
+
+            let cost = cost_by_tile[&tile];
+            for (dx, dy) in [
+                (-1, 0),
+                (1, 0),
+                (0, -1),
+                (0, 1),
+                (-1, -1),
+                (-1, 1),
+                (1, -1),
+                (1, 1),
+            ] {
+                let (x, y) = match (tile.0.checked_add_signed(dx), tile.1.checked_add_signed(dy)) {
+                    (Some(x), Some(y)) => (x, y),
+                    _ => continue,
+                };
+
+                if !self.is_walkable(x, y, size) {
+                    continue;
+                }
+
+                let is_diagonal = dx != 0 && dy != 0;
+                if is_diagonal
+                    && (!self.is_walkable(x, tile.1, size) || !self.is_walkable(tile.0, y, size))
+                {
+                    continue;
+                }
+
+                let next_cost = cost
+                    + if is_diagonal {
+                        DIAGONAL_COST
+                    } else {
+                        ORTHOGONAL_COST
+                    };
+                if matches!(cost_by_tile.get(&(x, y)), Some(previous_cost) if *previous_cost <= next_cost)
+                {
+                    continue;
+                }
+
+                cost_by_tile.insert((x, y), next_cost);
+                previous_by_tile.insert((x, y), tile);
+                open_tiles.push(Reverse((next_cost + heuristic((x, y)), (x, y))));
+            }
+        }
+
+        None
+    }
+}
+
+#[test]
+fn test_find_path() {
+    use super::Tile;
+
+    // Rows from top to bottom, with a wall between the start on the left and the goal on the right.
+    let rows = [
+        "#######", //
+        "#..#..#", //
+        "#..#..#", //
+        "#.....#", //
+        "#######", //
+    ];
+    let mut layer = vec![vec![None; rows.len()]; rows[0].len()];
+    for (row_index, row) in rows.iter().enumerate() {
+        for (x, character) in row.chars().enumerate() {
+            if character == '#' {
+                layer[x][rows.len() - 1 - row_index] = Some(Tile::WALL);
+            }
+        }
+    }
+    let zone = Zone {
+        columns: rows[0].len(),
+        rows: rows.len(),
+        layers: vec![layer],
+        player_spawn: (1, 3),
+        enemy_spawns: vec![],
+    };
+
+    let path = zone.find_path((2, 3), (4, 3), 1).unwrap();
+    assert_eq!(path, vec![(2, 2), (2, 1), (3, 1), (4, 1), (4, 2), (4, 3)]);
+
+    assert_eq!(zone.find_path((2, 3), (4, 3), 2), None);
+    assert_eq!(zone.find_path((2, 3), (3, 3), 1), None);
+    assert_eq!(zone.tile_at(zone.tile_position(4, 3)), Some((4, 3)));
+}